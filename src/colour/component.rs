@@ -0,0 +1,80 @@
+//! Channel types for [`Rgb`](crate::colour::Rgb).
+
+use std::fmt;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A type that can hold one channel of a colour: `u8` and `u16` for 8- and
+/// 16-bit images, and `f32` and `f64` for floating-point pipelines.
+///
+/// Integer channels run from zero to their maximum value. Float channels
+/// nominally run from `0.0` to `1.0` but may go beyond it, for HDR colours
+/// or colours outside the sRGB gamut.
+pub trait Component: sealed::Sealed + Copy + PartialOrd + Default + fmt::Debug + Send + Sync + 'static {
+    /// The value of a channel at full intensity.
+    const MAX: Self;
+
+    /// For integer types, the number of steps from zero to [`Self::MAX`];
+    /// `None` for float types, which need no rounding.
+    const LEVELS: Option<f64>;
+
+    /// The channel as a fraction of full intensity.
+    fn to_unit(self) -> f64;
+
+    /// A channel from a fraction of full intensity. Integer types round to
+    /// the nearest value and clip to their range, with NaN giving zero;
+    /// float types keep the value as it is.
+    fn from_unit(unit: f64) -> Self;
+}
+
+macro_rules! integer_component {
+    ($($t:ty),*) => {$(
+        impl Component for $t {
+            const MAX: Self = <$t>::MAX;
+            const LEVELS: Option<f64> = Some(<$t>::MAX as f64);
+
+            fn to_unit(self) -> f64 {
+                self as f64 / <$t>::MAX as f64
+            }
+
+            fn from_unit(unit: f64) -> Self {
+                (unit.clamp(0.0, 1.0) * <$t>::MAX as f64).round() as $t
+            }
+        }
+    )*};
+}
+
+integer_component!(u8, u16);
+
+impl Component for f32 {
+    const MAX: Self = 1.0;
+    const LEVELS: Option<f64> = None;
+
+    fn to_unit(self) -> f64 {
+        self as f64
+    }
+
+    fn from_unit(unit: f64) -> Self {
+        unit as f32
+    }
+}
+
+impl Component for f64 {
+    const MAX: Self = 1.0;
+    const LEVELS: Option<f64> = None;
+
+    fn to_unit(self) -> f64 {
+        self
+    }
+
+    fn from_unit(unit: f64) -> Self {
+        unit
+    }
+}
@@ -0,0 +1,292 @@
+//! Compositing and blending, following the W3C Compositing and Blending
+//! Level 1 specification.
+//!
+//! Like the specification, these work on gamma-encoded sRGB channels.
+
+use crate::colour::{convert::Vec3, Rgba, RGB};
+
+/// A Porter–Duff compositing operator, deciding how much of the source and
+/// of the backdrop show through where they overlap.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum PorterDuff {
+    /// Neither is shown.
+    Clear,
+    /// Only the source is shown.
+    Copy,
+    /// Only the backdrop is shown.
+    Destination,
+    /// The source is drawn over the backdrop.
+    #[default]
+    SourceOver,
+    /// The backdrop is drawn over the source.
+    DestinationOver,
+    /// The source, where the backdrop is.
+    SourceIn,
+    /// The backdrop, where the source is.
+    DestinationIn,
+    /// The source, where the backdrop is not.
+    SourceOut,
+    /// The backdrop, where the source is not.
+    DestinationOut,
+    /// The source where the backdrop is, over the backdrop.
+    SourceAtop,
+    /// The backdrop where the source is, over the source.
+    DestinationAtop,
+    /// The source where the backdrop is not, and the backdrop where the
+    /// source is not.
+    Xor,
+}
+
+impl PorterDuff {
+    /// The fractions of the source and backdrop kept, given their alphas.
+    fn factors(self, source: f64, backdrop: f64) -> (f64, f64) {
+        match self {
+            Self::Clear => (0.0, 0.0),
+            Self::Copy => (1.0, 0.0),
+            Self::Destination => (0.0, 1.0),
+            Self::SourceOver => (1.0, 1.0 - source),
+            Self::DestinationOver => (1.0 - backdrop, 1.0),
+            Self::SourceIn => (backdrop, 0.0),
+            Self::DestinationIn => (0.0, source),
+            Self::SourceOut => (1.0 - backdrop, 0.0),
+            Self::DestinationOut => (0.0, 1.0 - source),
+            Self::SourceAtop => (backdrop, 1.0 - source),
+            Self::DestinationAtop => (1.0 - backdrop, source),
+            Self::Xor => (1.0 - backdrop, 1.0 - source),
+        }
+    }
+}
+
+/// A blend mode, deciding the colour where a source is painted over a
+/// backdrop.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// The source colour.
+    #[default]
+    Normal,
+    /// The product of the colours, which always darkens.
+    Multiply,
+    /// The complement of the product of the complements, which always
+    /// lightens.
+    Screen,
+    /// Multiplies or screens depending on the backdrop.
+    Overlay,
+    /// The darker of each channel.
+    Darken,
+    /// The lighter of each channel.
+    Lighten,
+    /// Brightens the backdrop to reflect the source.
+    ColourDodge,
+    /// Darkens the backdrop to reflect the source.
+    ColourBurn,
+    /// Multiplies or screens depending on the source.
+    HardLight,
+    /// Darkens or lightens depending on the source, more gently than
+    /// [`BlendMode::HardLight`].
+    SoftLight,
+    /// The absolute difference of each channel.
+    Difference,
+    /// Like [`BlendMode::Difference`], with lower contrast.
+    Exclusion,
+    /// The source's hue with the backdrop's saturation and luminosity.
+    Hue,
+    /// The source's saturation with the backdrop's hue and luminosity.
+    Saturation,
+    /// The source's hue and saturation with the backdrop's luminosity.
+    Colour,
+    /// The source's luminosity with the backdrop's hue and saturation.
+    Luminosity,
+}
+
+impl BlendMode {
+    /// The blending function `B(Cb, Cs)` over channels in `0.0..=1.0`.
+    fn apply(self, backdrop: Vec3, source: Vec3) -> Vec3 {
+        let separable = |f: fn(f64, f64) -> f64| {
+            [0, 1, 2].map(|i| f(backdrop[i], source[i]))
+        };
+
+        match self {
+            Self::Normal => source,
+            Self::Multiply => separable(multiply),
+            Self::Screen => separable(screen),
+            Self::Overlay => separable(|cb, cs| hard_light(cs, cb)),
+            Self::Darken => separable(f64::min),
+            Self::Lighten => separable(f64::max),
+            Self::ColourDodge => separable(colour_dodge),
+            Self::ColourBurn => separable(colour_burn),
+            Self::HardLight => separable(hard_light),
+            Self::SoftLight => separable(soft_light),
+            Self::Difference => separable(|cb, cs| (cb - cs).abs()),
+            Self::Exclusion => separable(|cb, cs| cb + cs - 2.0 * cb * cs),
+            Self::Hue => set_lum(set_sat(source, sat(backdrop)), lum(backdrop)),
+            Self::Saturation => set_lum(set_sat(backdrop, sat(source)), lum(backdrop)),
+            Self::Colour => set_lum(source, lum(backdrop)),
+            Self::Luminosity => set_lum(backdrop, lum(source)),
+        }
+    }
+}
+
+fn multiply(cb: f64, cs: f64) -> f64 {
+    cb * cs
+}
+
+fn screen(cb: f64, cs: f64) -> f64 {
+    cb + cs - cb * cs
+}
+
+fn hard_light(cb: f64, cs: f64) -> f64 {
+    if cs <= 0.5 {
+        multiply(cb, 2.0 * cs)
+    } else {
+        screen(cb, 2.0 * cs - 1.0)
+    }
+}
+
+fn colour_dodge(cb: f64, cs: f64) -> f64 {
+    if cb == 0.0 {
+        0.0
+    } else if cs >= 1.0 {
+        1.0
+    } else {
+        (cb / (1.0 - cs)).min(1.0)
+    }
+}
+
+fn colour_burn(cb: f64, cs: f64) -> f64 {
+    if cb >= 1.0 {
+        1.0
+    } else if cs == 0.0 {
+        0.0
+    } else {
+        1.0 - ((1.0 - cb) / cs).min(1.0)
+    }
+}
+
+fn soft_light(cb: f64, cs: f64) -> f64 {
+    if cs <= 0.5 {
+        cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+    } else {
+        let d = if cb <= 0.25 {
+            ((16.0 * cb - 12.0) * cb + 4.0) * cb
+        } else {
+            cb.sqrt()
+        };
+        cb + (2.0 * cs - 1.0) * (d - cb)
+    }
+}
+
+fn lum([r, g, b]: Vec3) -> f64 {
+    0.3 * r + 0.59 * g + 0.11 * b
+}
+
+/// Brings a colour with the right luminosity back into `0.0..=1.0`
+/// without changing its luminosity.
+fn clip_colour(c: Vec3) -> Vec3 {
+    let l = lum(c);
+    let min = c.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = c.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let mut c = c;
+    if min < 0.0 {
+        c = c.map(|x| l + (x - l) * l / (l - min));
+    }
+    if max > 1.0 {
+        c = c.map(|x| l + (x - l) * (1.0 - l) / (max - l));
+    }
+    c
+}
+
+fn set_lum(c: Vec3, l: f64) -> Vec3 {
+    let d = l - lum(c);
+    clip_colour(c.map(|x| x + d))
+}
+
+fn sat(c: Vec3) -> f64 {
+    let min = c.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = c.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    max - min
+}
+
+/// Stretches the channels so the spread between the largest and smallest
+/// is `s`, with the smallest at zero.
+fn set_sat(c: Vec3, s: f64) -> Vec3 {
+    let min = c.iter().copied().fold(f64::INFINITY, f64::min);
+    let range = sat(c);
+    if range > 0.0 {
+        c.map(|x| (x - min) * s / range)
+    } else {
+        [0.0; 3]
+    }
+}
+
+impl RGB {
+    /// Paints this colour over `backdrop` with the given blend mode. Both are
+    /// opaque, so the result is the blend mode's colour.
+    pub fn blend_with(&self, backdrop: RGB, mode: BlendMode) -> RGB {
+        RGB::from_unit(mode.apply(backdrop.to_unit(), self.to_unit()))
+    }
+}
+
+impl Rgba {
+    /// Composites this colour onto `backdrop` with a Porter–Duff operator.
+    pub fn composite(&self, backdrop: Rgba, operator: PorterDuff) -> Rgba {
+        let (source, alpha_s) = premultiplied(*self);
+        let (backdrop, alpha_b) = premultiplied(backdrop);
+        let (fa, fb) = operator.factors(alpha_s, alpha_b);
+        let colour = [0, 1, 2].map(|i| source[i] * fa + backdrop[i] * fb);
+        unpremultiplied(colour, alpha_s * fa + alpha_b * fb)
+    }
+
+    /// This colour drawn over `backdrop`: Porter–Duff source-over.
+    pub fn over(&self, backdrop: Rgba) -> Rgba {
+        self.composite(backdrop, PorterDuff::SourceOver)
+    }
+
+    /// This colour where `backdrop` is: Porter–Duff source-in.
+    pub fn inside(&self, backdrop: Rgba) -> Rgba {
+        self.composite(backdrop, PorterDuff::SourceIn)
+    }
+
+    /// This colour where `backdrop` is not: Porter–Duff source-out.
+    pub fn outside(&self, backdrop: Rgba) -> Rgba {
+        self.composite(backdrop, PorterDuff::SourceOut)
+    }
+
+    /// This colour where `backdrop` is, drawn over it: Porter–Duff
+    /// source-atop.
+    pub fn atop(&self, backdrop: Rgba) -> Rgba {
+        self.composite(backdrop, PorterDuff::SourceAtop)
+    }
+
+    /// This colour and `backdrop` where the other is not: Porter–Duff xor.
+    pub fn xor(&self, backdrop: Rgba) -> Rgba {
+        self.composite(backdrop, PorterDuff::Xor)
+    }
+
+    /// Paints this colour over `backdrop` with the given blend mode, then
+    /// composites it source-over. Where the backdrop is transparent the
+    /// source shows unblended.
+    pub fn blend_with(&self, backdrop: Rgba, mode: BlendMode) -> Rgba {
+        let ([rs, gs, bs, alpha_s], [rb, gb, bb, alpha_b]) = (self.to_unit(), backdrop.to_unit());
+        let (source, backdrop) = ([rs, gs, bs], [rb, gb, bb]);
+        let blended = mode.apply(backdrop, source);
+        let colour = [0, 1, 2].map(|i| {
+            let mixed = (1.0 - alpha_b) * source[i] + alpha_b * blended[i];
+            alpha_s * mixed + alpha_b * backdrop[i] * (1.0 - alpha_s)
+        });
+        unpremultiplied(colour, alpha_s + alpha_b * (1.0 - alpha_s))
+    }
+}
+
+/// The colour's channels, premultiplied by alpha, and its alpha.
+fn premultiplied(colour: Rgba) -> (Vec3, f64) {
+    let [r, g, b, a] = colour.to_unit();
+    ([r * a, g * a, b * a], a)
+}
+
+fn unpremultiplied(colour: Vec3, alpha: f64) -> Rgba {
+    if alpha <= 0.0 {
+        return Rgba::TRANSPARENT;
+    }
+    let [r, g, b] = colour.map(|c| c / alpha);
+    Rgba::from_unit([r, g, b, alpha])
+}
@@ -0,0 +1,388 @@
+//! Colour space maths shared by the colour types and the CSS parser.
+//!
+//! Everything here works on plain `f64` triples. Matrices are row-major and
+//! multiply column vectors, and the constants follow CSS Color Level 4.
+
+use std::sync::OnceLock;
+
+pub(crate) type Vec3 = [f64; 3];
+pub(crate) type Mat3 = [[f64; 3]; 3];
+
+pub(crate) fn mul(m: &Mat3, v: Vec3) -> Vec3 {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+pub(crate) fn mat_mul(a: &Mat3, b: &Mat3) -> Mat3 {
+    let mut out = [[0.0; 3]; 3];
+    for (i, row) in out.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    out
+}
+
+pub(crate) fn invert(m: &Mat3) -> Mat3 {
+    let cof = |r0: usize, r1: usize, c0: usize, c1: usize| {
+        m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+    };
+    let c = [
+        [cof(1, 2, 1, 2), -cof(1, 2, 0, 2), cof(1, 2, 0, 1)],
+        [-cof(0, 2, 1, 2), cof(0, 2, 0, 2), -cof(0, 2, 0, 1)],
+        [cof(0, 1, 1, 2), -cof(0, 1, 0, 2), cof(0, 1, 0, 1)],
+    ];
+    let det = m[0][0] * c[0][0] + m[0][1] * c[0][1] + m[0][2] * c[0][2];
+
+    // The inverse is the transposed cofactor matrix over the determinant.
+    let mut out = [[0.0; 3]; 3];
+    for (i, row) in out.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = c[j][i] / det;
+        }
+    }
+    out
+}
+
+/// Converts a chromaticity coordinate to XYZ with `Y = 1`.
+pub(crate) fn xy_to_xyz(x: f64, y: f64) -> Vec3 {
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+pub(crate) const D50_XY: (f64, f64) = (0.3457, 0.3585);
+pub(crate) const D65_XY: (f64, f64) = (0.3127, 0.3290);
+
+pub(crate) fn d50() -> Vec3 {
+    xy_to_xyz(D50_XY.0, D50_XY.1)
+}
+
+pub(crate) fn d65() -> Vec3 {
+    xy_to_xyz(D65_XY.0, D65_XY.1)
+}
+
+/// Builds the linear RGB to XYZ matrix for a set of red, green and blue
+/// primaries and a white point, all given as chromaticities.
+pub(crate) fn rgb_to_xyz_matrix(primaries: [(f64, f64); 3], white: Vec3) -> Mat3 {
+    let [r, g, b] = [
+        xy_to_xyz(primaries[0].0, primaries[0].1),
+        xy_to_xyz(primaries[1].0, primaries[1].1),
+        xy_to_xyz(primaries[2].0, primaries[2].1),
+    ];
+    let columns = [
+        [r[0], g[0], b[0]],
+        [r[1], g[1], b[1]],
+        [r[2], g[2], b[2]],
+    ];
+    let s = mul(&invert(&columns), white);
+    [
+        [s[0] * r[0], s[1] * g[0], s[2] * b[0]],
+        [s[0] * r[1], s[1] * g[1], s[2] * b[1]],
+        [s[0] * r[2], s[1] * g[2], s[2] * b[2]],
+    ]
+}
+
+const BRADFORD: Mat3 = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// Builds a Bradford chromatic adaptation matrix taking XYZ values relative
+/// to the `from` white point to ones relative to `to`.
+pub(crate) fn bradford(from: Vec3, to: Vec3) -> Mat3 {
+    let src = mul(&BRADFORD, from);
+    let dst = mul(&BRADFORD, to);
+    let scale = [
+        [dst[0] / src[0], 0.0, 0.0],
+        [0.0, dst[1] / src[1], 0.0],
+        [0.0, 0.0, dst[2] / src[2]],
+    ];
+    mat_mul(&invert(&BRADFORD), &mat_mul(&scale, &BRADFORD))
+}
+
+/// Applies `f` to the magnitude of `x`, keeping its sign, so transfer
+/// functions extend to out-of-gamut values.
+fn signed(x: f64, f: impl Fn(f64) -> f64) -> f64 {
+    x.signum() * f(x.abs())
+}
+
+/// The sRGB electro-optical transfer function: gamma-encoded to linear.
+pub(crate) fn srgb_to_linear(c: f64) -> f64 {
+    signed(c, |c| {
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    })
+}
+
+/// The inverse sRGB transfer function: linear to gamma-encoded.
+pub(crate) fn linear_to_srgb(c: f64) -> f64 {
+    signed(c, |c| {
+        if c <= 0.0031308 {
+            c * 12.92
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        }
+    })
+}
+
+pub(crate) fn a98_to_linear(c: f64) -> f64 {
+    signed(c, |c| c.powf(563.0 / 256.0))
+}
+
+pub(crate) fn linear_to_a98(c: f64) -> f64 {
+    signed(c, |c| c.powf(256.0 / 563.0))
+}
+
+pub(crate) fn prophoto_to_linear(c: f64) -> f64 {
+    signed(c, |c| if c <= 16.0 / 512.0 { c / 16.0 } else { c.powf(1.8) })
+}
+
+pub(crate) fn linear_to_prophoto(c: f64) -> f64 {
+    signed(c, |c| {
+        if c < 1.0 / 512.0 {
+            c * 16.0
+        } else {
+            c.powf(1.0 / 1.8)
+        }
+    })
+}
+
+const REC2020_ALPHA: f64 = 1.099_296_826_809_44;
+const REC2020_BETA: f64 = 0.018_053_968_510_807;
+
+pub(crate) fn rec2020_to_linear(c: f64) -> f64 {
+    signed(c, |c| {
+        if c < REC2020_BETA * 4.5 {
+            c / 4.5
+        } else {
+            ((c + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+        }
+    })
+}
+
+pub(crate) fn linear_to_rec2020(c: f64) -> f64 {
+    signed(c, |c| {
+        if c < REC2020_BETA {
+            c * 4.5
+        } else {
+            REC2020_ALPHA * c.powf(0.45) - (REC2020_ALPHA - 1.0)
+        }
+    })
+}
+
+pub(crate) const SRGB_PRIMARIES: [(f64, f64); 3] = [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)];
+pub(crate) const DISPLAY_P3_PRIMARIES: [(f64, f64); 3] =
+    [(0.680, 0.320), (0.265, 0.690), (0.150, 0.060)];
+pub(crate) const A98_PRIMARIES: [(f64, f64); 3] = [(0.64, 0.33), (0.21, 0.71), (0.15, 0.06)];
+pub(crate) const PROPHOTO_PRIMARIES: [(f64, f64); 3] = [
+    (0.734_699, 0.265_301),
+    (0.159_597, 0.840_403),
+    (0.036_598, 0.000_105),
+];
+pub(crate) const REC2020_PRIMARIES: [(f64, f64); 3] =
+    [(0.708, 0.292), (0.170, 0.797), (0.131, 0.046)];
+/// The ACES AP1 primaries, used by ACEScg.
+pub(crate) const AP1_PRIMARIES: [(f64, f64); 3] =
+    [(0.713, 0.293), (0.165, 0.830), (0.128, 0.044)];
+/// The ACES white point, close to but not exactly D60.
+pub(crate) const ACES_WHITE_XY: (f64, f64) = (0.32168, 0.33767);
+
+fn srgb_to_xyz_matrix() -> &'static Mat3 {
+    static MATRIX: OnceLock<Mat3> = OnceLock::new();
+    MATRIX.get_or_init(|| rgb_to_xyz_matrix(SRGB_PRIMARIES, d65()))
+}
+
+fn xyz_to_srgb_matrix() -> &'static Mat3 {
+    static MATRIX: OnceLock<Mat3> = OnceLock::new();
+    MATRIX.get_or_init(|| invert(srgb_to_xyz_matrix()))
+}
+
+/// Adapts D65-relative XYZ to D50.
+pub(crate) fn d65_to_d50(xyz: Vec3) -> Vec3 {
+    static MATRIX: OnceLock<Mat3> = OnceLock::new();
+    mul(MATRIX.get_or_init(|| bradford(d65(), d50())), xyz)
+}
+
+/// Adapts D50-relative XYZ to D65.
+pub(crate) fn d50_to_d65(xyz: Vec3) -> Vec3 {
+    static MATRIX: OnceLock<Mat3> = OnceLock::new();
+    mul(MATRIX.get_or_init(|| bradford(d50(), d65())), xyz)
+}
+
+pub(crate) fn linear_srgb_to_xyz(rgb: Vec3) -> Vec3 {
+    mul(srgb_to_xyz_matrix(), rgb)
+}
+
+pub(crate) fn xyz_to_linear_srgb(xyz: Vec3) -> Vec3 {
+    mul(xyz_to_srgb_matrix(), xyz)
+}
+
+/// Splits sRGB channels into hue, the largest channel and the chroma
+/// (largest minus smallest), shared by HSL and HSV.
+pub(crate) fn hue_max_chroma([r, g, b]: [f64; 3]) -> (f64, f64, f64) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+
+    let hue = if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / chroma)
+    } else if max == g {
+        60.0 * ((b - r) / chroma + 2.0)
+    } else {
+        60.0 * ((r - g) / chroma + 4.0)
+    };
+
+    (hue.rem_euclid(360.0), max, chroma)
+}
+
+pub(crate) fn srgb_to_hsl(rgb: Vec3) -> Vec3 {
+    let (hue, max, chroma) = hue_max_chroma(rgb);
+    let lightness = max - chroma / 2.0;
+    let saturation = if lightness <= 0.0 || lightness >= 1.0 {
+        0.0
+    } else {
+        chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+    };
+    [hue, saturation, lightness]
+}
+
+pub(crate) fn hsl_to_srgb(hue: f64, saturation: f64, lightness: f64) -> Vec3 {
+    let hue = hue.rem_euclid(360.0);
+    let f = |n: f64| {
+        let k = (n + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+pub(crate) fn hwb_to_srgb(hue: f64, whiteness: f64, blackness: f64) -> Vec3 {
+    if whiteness + blackness >= 1.0 {
+        let grey = whiteness / (whiteness + blackness);
+        return [grey; 3];
+    }
+    let [r, g, b] = hsl_to_srgb(hue, 1.0, 0.5);
+    let scale = 1.0 - whiteness - blackness;
+    [r * scale + whiteness, g * scale + whiteness, b * scale + whiteness]
+}
+
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
+
+/// Converts XYZ relative to `white` to CIE Lab.
+pub(crate) fn xyz_to_lab(xyz: Vec3, white: Vec3) -> Vec3 {
+    let f = |t: f64| {
+        if t > LAB_EPSILON {
+            t.cbrt()
+        } else {
+            (LAB_KAPPA * t + 16.0) / 116.0
+        }
+    };
+    let fx = f(xyz[0] / white[0]);
+    let fy = f(xyz[1] / white[1]);
+    let fz = f(xyz[2] / white[2]);
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Converts CIE Lab to XYZ relative to `white`.
+pub(crate) fn lab_to_xyz(lab: Vec3, white: Vec3) -> Vec3 {
+    scaled_lab_to_xyz(lab, white, 1.0)
+}
+
+/// Converts CIE Lab to XYZ relative to `white`, divided by the cube of
+/// `scale`, which keeps extreme values from overflowing.
+fn scaled_lab_to_xyz(lab: Vec3, white: Vec3, scale: f64) -> Vec3 {
+    let fy = (lab[0] + 16.0) / 116.0;
+    let fx = lab[1] / 500.0 + fy;
+    let fz = fy - lab[2] / 200.0;
+    let cube_or_linear = |f: f64| {
+        if f.powi(3) > LAB_EPSILON {
+            (f / scale).powi(3)
+        } else {
+            (116.0 * f - 16.0) / LAB_KAPPA / scale.powi(3)
+        }
+    };
+    let y = if lab[0] > LAB_KAPPA * LAB_EPSILON {
+        (fy / scale).powi(3)
+    } else {
+        lab[0] / LAB_KAPPA / scale.powi(3)
+    };
+    [
+        cube_or_linear(fx) * white[0],
+        y * white[1],
+        cube_or_linear(fz) * white[2],
+    ]
+}
+
+/// Converts D50-relative CIE Lab to Oklab.
+///
+/// XYZ grows with the cube of Lab and Oklab with the cube root of XYZ, so
+/// Lab's scale is taken out before converting and put back after. Values
+/// far too large to describe a real colour then convert without
+/// overflowing.
+pub(crate) fn lab_to_oklab(lab: Vec3) -> Vec3 {
+    let fy = (lab[0] + 16.0) / 116.0;
+    let scale = [fy, lab[1] / 500.0 + fy, fy - lab[2] / 200.0]
+        .iter()
+        .fold(1.0_f64, |scale, f| scale.max(f.abs()));
+    let xyz = d50_to_d65(scaled_lab_to_xyz(lab, d50(), scale));
+    xyz_to_oklab(xyz).map(|c| c * scale)
+}
+
+/// Converts D65-relative XYZ to Oklab, taking the scale of extreme values
+/// out first, as [`lab_to_oklab`] does.
+pub(crate) fn xyz_to_oklab(xyz: Vec3) -> Vec3 {
+    let scale = xyz.iter().fold(1.0_f64, |scale, c| scale.max(c.abs()));
+    let lab = linear_srgb_to_oklab(xyz_to_linear_srgb(xyz.map(|c| c / scale)));
+    lab.map(|c| c * scale.cbrt())
+}
+
+/// Converts a cylindrical lightness/chroma/hue triple to its rectangular
+/// form. The hue is in degrees.
+pub(crate) fn polar_to_rect(lch: Vec3) -> Vec3 {
+    let (sin, cos) = lch[2].to_radians().sin_cos();
+    [lch[0], lch[1] * cos, lch[1] * sin]
+}
+
+/// Converts a rectangular lightness/a/b triple to lightness, chroma and a
+/// hue in degrees within `0..360`.
+pub(crate) fn rect_to_polar(lab: Vec3) -> Vec3 {
+    let hue = lab[2].atan2(lab[1]).to_degrees().rem_euclid(360.0);
+    [lab[0], lab[1].hypot(lab[2]), hue]
+}
+
+pub(crate) fn linear_srgb_to_oklab(rgb: Vec3) -> Vec3 {
+    let l = 0.412_221_470_8 * rgb[0] + 0.536_332_536_3 * rgb[1] + 0.051_445_992_9 * rgb[2];
+    let m = 0.211_903_498_2 * rgb[0] + 0.680_699_545_1 * rgb[1] + 0.107_396_956_6 * rgb[2];
+    let s = 0.088_302_461_9 * rgb[0] + 0.281_718_837_6 * rgb[1] + 0.629_978_700_5 * rgb[2];
+
+    let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+    [
+        0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+        1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+        0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+    ]
+}
+
+pub(crate) fn oklab_to_linear_srgb(lab: Vec3) -> Vec3 {
+    let l = lab[0] + 0.396_337_777_4 * lab[1] + 0.215_803_757_3 * lab[2];
+    let m = lab[0] - 0.105_561_345_8 * lab[1] - 0.063_854_172_8 * lab[2];
+    let s = lab[0] - 0.089_484_177_5 * lab[1] - 1.291_485_548_0 * lab[2];
+
+    let (l, m, s) = (l.powi(3), m.powi(3), s.powi(3));
+
+    [
+        4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s,
+        -1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s,
+        -0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s,
+    ]
+}
@@ -0,0 +1,650 @@
+//! Parsing CSS Color Level 4 colour values.
+//!
+//! [`parse_css_colour`] understands hex colours, named colours, `transparent`,
+//! and the `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()`, `lab()`, `lch()`,
+//! `oklab()`, `oklch()` and `color()` functions, in both the modern
+//! space-separated syntax and the legacy comma-separated one where CSS allows
+//! it.
+
+use crate::colour::{
+    convert::{self, Vec3},
+    parse::{parse_hex, ColourParseError},
+    gamut, AdobeRgb, DisplayP3, GamutMapping, Oklab, ProPhotoRgb, Rec2020, RgbSpace, Rgba, Srgb,
+    WideRgb, RGB,
+};
+
+use std::ops::Range;
+
+/// A colour parsed from CSS, converted to sRGB.
+///
+/// The channels are gamma-encoded sRGB in `0.0..=1.0` for colours inside the
+/// sRGB gamut, and may fall outside that range for colours from wider spaces
+/// such as `lab()` or `color(display-p3 ...)`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CssColour {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    pub alpha: f64,
+    /// The colour as given to `lab()`, `lch()`, `oklab()`, `oklch()` or
+    /// `color()`, in Oklab. Gamut mapping starts from here, since extreme
+    /// values overflow on their way to sRGB channels.
+    origin: Option<Oklab>,
+}
+
+impl CssColour {
+    pub fn new(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+            origin: None,
+        }
+    }
+
+    fn with_origin(mut self, origin: Vec3) -> Self {
+        self.origin = Some(Oklab::from_array(origin));
+        self
+    }
+
+    pub(crate) fn origin(&self) -> Option<Oklab> {
+        self.origin
+    }
+
+    fn from_srgb([red, green, blue]: Vec3, alpha: f64) -> Self {
+        Self::new(red, green, blue, alpha)
+    }
+
+    fn from_linear_srgb(rgb: Vec3, alpha: f64) -> Self {
+        Self::from_srgb(rgb.map(convert::linear_to_srgb), alpha)
+    }
+
+    fn from_xyz_d65(xyz: Vec3, alpha: f64) -> Self {
+        Self::from_linear_srgb(convert::xyz_to_linear_srgb(xyz), alpha)
+            .with_origin(convert::xyz_to_oklab(xyz))
+    }
+
+    fn from_xyz_d50(xyz: Vec3, alpha: f64) -> Self {
+        Self::from_xyz_d65(convert::d50_to_d65(xyz), alpha)
+    }
+
+    fn from_lab(lab: Vec3, alpha: f64) -> Self {
+        Self::from_xyz_d50(convert::lab_to_xyz(lab, convert::d50()), alpha)
+            .with_origin(convert::lab_to_oklab(lab))
+    }
+
+    fn from_oklab(lab: Vec3, alpha: f64) -> Self {
+        Self::from_linear_srgb(convert::oklab_to_linear_srgb(lab), alpha).with_origin(lab)
+    }
+
+    fn from_space<S: RgbSpace>(rgb: Vec3, alpha: f64) -> Self {
+        let colour = WideRgb::<S>::from_array(rgb);
+        Self::from_srgb(colour.convert::<Srgb>().to_array(), alpha)
+            .with_origin(gamut::oklab_origin(colour).to_array())
+    }
+
+    /// Whether every channel lies within the sRGB gamut.
+    pub fn in_srgb_gamut(&self) -> bool {
+        [self.red, self.green, self.blue]
+            .iter()
+            .all(|c| (0.0..=1.0).contains(c))
+    }
+
+    /// Converts to an opaque [`RGB`], mapping colours outside the sRGB gamut
+    /// into it as CSS does and dropping alpha.
+    pub fn to_rgb(&self) -> RGB {
+        self.to_rgba().rgb()
+    }
+
+    /// Converts to an [`Rgba`], mapping colours outside the sRGB gamut into
+    /// it as CSS does.
+    pub fn to_rgba(&self) -> Rgba {
+        let colour = self.to_gamut(GamutMapping::Css);
+        Rgba::from_unit([colour.red, colour.green, colour.blue, colour.alpha])
+    }
+}
+
+/// Parses any CSS Color Level 4 colour value.
+///
+/// Errors carry the byte span of the offending part of `input`.
+pub fn parse_css_colour(input: &str) -> Result<CssColour, ColourParseError> {
+    let tokens = tokenise(input)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        end: input.len(),
+    };
+
+    let colour = parser.colour()?;
+    match parser.tokens.get(parser.pos) {
+        None => Ok(colour),
+        Some(token) => Err(ColourParseError::UnexpectedToken {
+            expected: "end of input",
+            span: token.span.clone(),
+        }),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    Ident(String),
+    Function(String),
+    Hash(String),
+    Number(f64),
+    Percentage(f64),
+    Dimension(f64, String),
+    Comma,
+    Slash,
+    CloseParen,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: Kind,
+    span: Range<usize>,
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()
+}
+
+fn tokenise(input: &str) -> Result<Vec<Token>, ColourParseError> {
+    let bytes = input.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    let name_end = |start: usize| {
+        input[start..]
+            .char_indices()
+            .find(|&(_, c)| !is_name_char(c))
+            .map_or(input.len(), |(idx, _)| start + idx)
+    };
+
+    while pos < input.len() {
+        let c = input[pos..].chars().next().unwrap();
+        let start = pos;
+
+        if c.is_whitespace() {
+            pos += c.len_utf8();
+            continue;
+        }
+
+        let kind = match c {
+            ',' => {
+                pos += 1;
+                Kind::Comma
+            }
+            '/' => {
+                pos += 1;
+                Kind::Slash
+            }
+            ')' => {
+                pos += 1;
+                Kind::CloseParen
+            }
+            '#' => {
+                pos = name_end(pos + 1);
+                Kind::Hash(input[start + 1..pos].to_string())
+            }
+            '0'..='9' | '.' | '+' | '-' if starts_number(&bytes[pos..]) => {
+                pos += number_len(&bytes[pos..]);
+                let value = input[start..pos]
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|value| value.is_finite())
+                    .ok_or(ColourParseError::UnexpectedToken {
+                        expected: "a number",
+                        span: start..pos,
+                    })?;
+                if bytes.get(pos) == Some(&b'%') {
+                    pos += 1;
+                    Kind::Percentage(value)
+                } else if input[pos..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+                    let unit_start = pos;
+                    pos = name_end(pos);
+                    Kind::Dimension(value, input[unit_start..pos].to_ascii_lowercase())
+                } else {
+                    Kind::Number(value)
+                }
+            }
+            c if is_name_char(c) => {
+                pos = name_end(pos);
+                let name = input[start..pos].to_ascii_lowercase();
+                if bytes.get(pos) == Some(&b'(') {
+                    pos += 1;
+                    Kind::Function(name)
+                } else {
+                    Kind::Ident(name)
+                }
+            }
+            found => {
+                return Err(ColourParseError::InvalidCharacter {
+                    found,
+                    position: pos,
+                })
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            span: start..pos,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Whether the bytes start with a CSS number rather than, say, an identifier
+/// beginning with `-`.
+fn starts_number(bytes: &[u8]) -> bool {
+    let digit = |idx: usize| bytes.get(idx).is_some_and(u8::is_ascii_digit);
+    match bytes[0] {
+        b'+' | b'-' => digit(1) || (bytes.get(1) == Some(&b'.') && digit(2)),
+        b'.' => digit(1),
+        _ => digit(0),
+    }
+}
+
+fn number_len(bytes: &[u8]) -> usize {
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let mut len = 0;
+    if matches!(bytes[0], b'+' | b'-') {
+        len += 1;
+    }
+    len += digits(len);
+    if bytes.get(len) == Some(&b'.') && bytes.get(len + 1).is_some_and(u8::is_ascii_digit) {
+        len += 1 + digits(len + 1);
+    }
+    if matches!(bytes.get(len), Some(b'e') | Some(b'E')) {
+        let sign = usize::from(matches!(bytes.get(len + 1), Some(b'+') | Some(b'-')));
+        if bytes.get(len + 1 + sign).is_some_and(u8::is_ascii_digit) {
+            len += 1 + sign + digits(len + 1 + sign);
+        }
+    }
+    len
+}
+
+/// The arguments of a colour function, split into the three channel
+/// components and an optional alpha.
+struct Arguments {
+    components: Vec<Token>,
+    alpha: Option<Token>,
+    legacy: bool,
+}
+
+/// How a percentage maps onto a component's number range.
+#[derive(Copy, Clone)]
+enum Percent {
+    /// `100%` is this number.
+    Scale(f64),
+    /// Percentages are not allowed.
+    Forbidden,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Result<Token, ColourParseError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or(ColourParseError::UnexpectedEnd { position: self.end })?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn colour(&mut self) -> Result<CssColour, ColourParseError> {
+        let token = match self.tokens.get(self.pos) {
+            Some(token) => token.clone(),
+            None => return Err(ColourParseError::Empty),
+        };
+        self.pos += 1;
+        let start = token.span.start;
+
+        match token.kind {
+            Kind::Hash(digits) => {
+                let [r, g, b, a] =
+                    parse_hex(&digits).map_err(|e| e.offset(start + 1))?;
+                let channel = |c: u8| c as f64 / 255.0;
+                Ok(CssColour::new(channel(r), channel(g), channel(b), channel(a)))
+            }
+            Kind::Ident(name) => keyword(&name).ok_or(ColourParseError::UnknownKeyword {
+                name,
+                span: token.span,
+            }),
+            Kind::Function(name) => self.function(&name, token.span),
+            _ => Err(ColourParseError::UnexpectedToken {
+                expected: "a colour",
+                span: token.span,
+            }),
+        }
+    }
+
+    fn function(&mut self, name: &str, span: Range<usize>) -> Result<CssColour, ColourParseError> {
+        match name {
+            "rgb" | "rgba" => self.rgb(),
+            "hsl" | "hsla" => self.hsl(),
+            "hwb" => self.hwb(),
+            "lab" => {
+                let (lab, alpha) = self.lab_like(100.0, 125.0)?;
+                let lab = [lab[0].max(0.0), lab[1], lab[2]];
+                Ok(CssColour::from_lab(lab, alpha))
+            }
+            "lch" => {
+                let (lch, alpha) = self.lch_like(100.0, 150.0)?;
+                Ok(CssColour::from_lab(convert::polar_to_rect(lch), alpha))
+            }
+            "oklab" => {
+                let (lab, alpha) = self.lab_like(1.0, 0.4)?;
+                let lab = [lab[0].max(0.0), lab[1], lab[2]];
+                Ok(CssColour::from_oklab(lab, alpha))
+            }
+            "oklch" => {
+                let (lch, alpha) = self.lch_like(1.0, 0.4)?;
+                Ok(CssColour::from_oklab(convert::polar_to_rect(lch), alpha))
+            }
+            "color" => self.color(),
+            _ => Err(ColourParseError::UnknownFunction {
+                name: name.to_string(),
+                span,
+            }),
+        }
+    }
+
+    /// Reads the arguments of a function up to and including its closing
+    /// parenthesis.
+    fn arguments(&mut self, allow_legacy: bool) -> Result<Arguments, ColourParseError> {
+        let mut components = Vec::new();
+        let mut alpha = None;
+        let mut legacy = false;
+        // In the legacy syntax, whether the last token was a component, so
+        // that a comma must come next rather than another component.
+        let mut expecting_comma = false;
+
+        loop {
+            let token = self.next()?;
+            match token.kind {
+                Kind::CloseParen => break,
+                Kind::Comma if allow_legacy && components.len() == 1 && !legacy => {
+                    legacy = true;
+                }
+                Kind::Comma if legacy && expecting_comma => {
+                    expecting_comma = false;
+                    if components.len() == 3 {
+                        alpha = Some(self.final_alpha()?);
+                        break;
+                    }
+                }
+                Kind::Slash if !legacy && components.len() == 3 => {
+                    alpha = Some(self.final_alpha()?);
+                    break;
+                }
+                Kind::Number(_) | Kind::Percentage(_) | Kind::Dimension(..) | Kind::Ident(_)
+                    if components.len() < 3 && !expecting_comma =>
+                {
+                    components.push(token);
+                    expecting_comma = legacy;
+                }
+                _ => {
+                    let expected = match (legacy, components.len()) {
+                        (true, n) if n < 3 && expecting_comma => "','",
+                        (_, n) if n < 3 => "a colour component",
+                        (true, _) => "',' or ')'",
+                        (false, _) => "'/' or ')'",
+                    };
+                    return Err(ColourParseError::UnexpectedToken {
+                        expected,
+                        span: token.span,
+                    });
+                }
+            }
+        }
+
+        if components.len() < 3 {
+            let span = self.tokens[self.pos - 1].span.clone();
+            return Err(ColourParseError::UnexpectedToken {
+                expected: "a colour component",
+                span,
+            });
+        }
+
+        if legacy {
+            let none = components
+                .iter()
+                .chain(alpha.as_ref())
+                .find(|t| matches!(t.kind, Kind::Ident(_)));
+            if let Some(token) = none {
+                return Err(ColourParseError::UnexpectedToken {
+                    expected: "a number or percentage",
+                    span: token.span.clone(),
+                });
+            }
+        }
+
+        Ok(Arguments {
+            components,
+            alpha,
+            legacy,
+        })
+    }
+
+    /// Reads the alpha component that ends an argument list, and the closing
+    /// parenthesis after it.
+    fn final_alpha(&mut self) -> Result<Token, ColourParseError> {
+        let alpha = self.next()?;
+        if !matches!(
+            alpha.kind,
+            Kind::Number(_) | Kind::Percentage(_) | Kind::Ident(_)
+        ) {
+            return Err(ColourParseError::UnexpectedToken {
+                expected: "an alpha value",
+                span: alpha.span,
+            });
+        }
+
+        let close = self.next()?;
+        if close.kind != Kind::CloseParen {
+            return Err(ColourParseError::UnexpectedToken {
+                expected: "')'",
+                span: close.span,
+            });
+        }
+        Ok(alpha)
+    }
+
+    fn rgb(&mut self) -> Result<CssColour, ColourParseError> {
+        let args = self.arguments(true)?;
+
+        if args.legacy {
+            let percentages = matches!(args.components[0].kind, Kind::Percentage(_));
+            for token in &args.components[1..] {
+                if matches!(token.kind, Kind::Percentage(_)) != percentages {
+                    return Err(ColourParseError::UnexpectedToken {
+                        expected: if percentages { "a percentage" } else { "a number" },
+                        span: token.span.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut rgb = [0.0; 3];
+        for (c, token) in rgb.iter_mut().zip(&args.components) {
+            *c = (component(token, Percent::Scale(255.0))? / 255.0).clamp(0.0, 1.0);
+        }
+        Ok(CssColour::from_srgb(rgb, alpha(args.alpha.as_ref())?))
+    }
+
+    fn hsl(&mut self) -> Result<CssColour, ColourParseError> {
+        let args = self.arguments(true)?;
+        let [h, s, l] = &args.components[..] else {
+            unreachable!()
+        };
+
+        if args.legacy {
+            for token in [s, l] {
+                if !matches!(token.kind, Kind::Percentage(_)) {
+                    return Err(ColourParseError::UnexpectedToken {
+                        expected: "a percentage",
+                        span: token.span.clone(),
+                    });
+                }
+            }
+        }
+
+        let hue = hue(h)?;
+        let saturation = (component(s, Percent::Scale(100.0))? / 100.0).clamp(0.0, 1.0);
+        let lightness = (component(l, Percent::Scale(100.0))? / 100.0).clamp(0.0, 1.0);
+        Ok(CssColour::from_srgb(
+            convert::hsl_to_srgb(hue, saturation, lightness),
+            alpha(args.alpha.as_ref())?,
+        ))
+    }
+
+    fn hwb(&mut self) -> Result<CssColour, ColourParseError> {
+        let args = self.arguments(false)?;
+        let [h, w, b] = &args.components[..] else {
+            unreachable!()
+        };
+
+        let hue = hue(h)?;
+        let whiteness = (component(w, Percent::Scale(100.0))? / 100.0).clamp(0.0, 1.0);
+        let blackness = (component(b, Percent::Scale(100.0))? / 100.0).clamp(0.0, 1.0);
+        Ok(CssColour::from_srgb(
+            convert::hwb_to_srgb(hue, whiteness, blackness),
+            alpha(args.alpha.as_ref())?,
+        ))
+    }
+
+    /// Reads the arguments of `lab()` or `oklab()`, where `100%` lightness is
+    /// `lightness` and `100%` on either axis is `axis`.
+    fn lab_like(&mut self, lightness: f64, axis: f64) -> Result<(Vec3, f64), ColourParseError> {
+        let args = self.arguments(false)?;
+        let [l, a, b] = &args.components[..] else {
+            unreachable!()
+        };
+
+        let lab = [
+            component(l, Percent::Scale(lightness))?,
+            component(a, Percent::Scale(axis))?,
+            component(b, Percent::Scale(axis))?,
+        ];
+        Ok((lab, alpha(args.alpha.as_ref())?))
+    }
+
+    /// Reads the arguments of `lch()` or `oklch()`, where `100%` lightness is
+    /// `lightness` and `100%` chroma is `chroma`.
+    fn lch_like(&mut self, lightness: f64, chroma: f64) -> Result<(Vec3, f64), ColourParseError> {
+        let args = self.arguments(false)?;
+        let [l, c, h] = &args.components[..] else {
+            unreachable!()
+        };
+
+        let lch = [
+            component(l, Percent::Scale(lightness))?.max(0.0),
+            component(c, Percent::Scale(chroma))?.max(0.0),
+            hue(h)?,
+        ];
+        Ok((lch, alpha(args.alpha.as_ref())?))
+    }
+
+    fn color(&mut self) -> Result<CssColour, ColourParseError> {
+        let space = self.next()?;
+        let name = match &space.kind {
+            Kind::Ident(name) => name.clone(),
+            _ => {
+                return Err(ColourParseError::UnexpectedToken {
+                    expected: "a colour space",
+                    span: space.span,
+                })
+            }
+        };
+
+        let args = self.arguments(false)?;
+        let mut values = [0.0; 3];
+        for (v, token) in values.iter_mut().zip(&args.components) {
+            *v = component(token, Percent::Scale(1.0))?;
+        }
+        let alpha = alpha(args.alpha.as_ref())?;
+
+        Ok(match name.as_str() {
+            "srgb" => CssColour::from_srgb(values, alpha)
+                .with_origin(gamut::oklab_origin(WideRgb::<Srgb>::from_array(values)).to_array()),
+            "srgb-linear" => CssColour::from_linear_srgb(values, alpha)
+                .with_origin(convert::xyz_to_oklab(convert::linear_srgb_to_xyz(values))),
+            "display-p3" => CssColour::from_space::<DisplayP3>(values, alpha),
+            "a98-rgb" => CssColour::from_space::<AdobeRgb>(values, alpha),
+            "prophoto-rgb" => CssColour::from_space::<ProPhotoRgb>(values, alpha),
+            "rec2020" => CssColour::from_space::<Rec2020>(values, alpha),
+            "xyz" | "xyz-d65" => CssColour::from_xyz_d65(values, alpha),
+            "xyz-d50" => CssColour::from_xyz_d50(values, alpha),
+            _ => {
+                return Err(ColourParseError::UnknownColourSpace {
+                    name,
+                    span: space.span,
+                })
+            }
+        })
+    }
+}
+
+/// Resolves a colour keyword: `transparent` or a named colour.
+fn keyword(name: &str) -> Option<CssColour> {
+    if name == "transparent" {
+        return Some(CssColour::new(0.0, 0.0, 0.0, 0.0));
+    }
+    Some(CssColour::from_srgb(RGB::from_name(name)?.to_unit(), 1.0))
+}
+
+/// Reads a number, percentage or `none` (which is zero) as a plain number.
+fn component(token: &Token, percent: Percent) -> Result<f64, ColourParseError> {
+    match (&token.kind, percent) {
+        (Kind::Number(n), _) => Ok(*n),
+        (Kind::Percentage(p), Percent::Scale(scale)) => Ok(p / 100.0 * scale),
+        (Kind::Ident(name), _) if name == "none" => Ok(0.0),
+        (Kind::Dimension(_, unit), _) => Err(ColourParseError::InvalidUnit {
+            unit: unit.clone(),
+            span: token.span.clone(),
+        }),
+        _ => Err(ColourParseError::UnexpectedToken {
+            expected: "a number",
+            span: token.span.clone(),
+        }),
+    }
+}
+
+/// Reads a hue as an angle in degrees. Bare numbers are degrees.
+fn hue(token: &Token) -> Result<f64, ColourParseError> {
+    match &token.kind {
+        Kind::Dimension(value, unit) => match unit.as_str() {
+            "deg" => Ok(*value),
+            "rad" => Ok(value.to_degrees()),
+            "grad" => Ok(value * 0.9),
+            "turn" => Ok(value * 360.0),
+            _ => Err(ColourParseError::InvalidUnit {
+                unit: unit.clone(),
+                span: token.span.clone(),
+            }),
+        },
+        _ => component(token, Percent::Forbidden),
+    }
+}
+
+/// Reads an optional alpha value, clamped to `0.0..=1.0`.
+fn alpha(token: Option<&Token>) -> Result<f64, ColourParseError> {
+    match token {
+        Some(token) => Ok(component(token, Percent::Scale(1.0))?.clamp(0.0, 1.0)),
+        None => Ok(1.0),
+    }
+}
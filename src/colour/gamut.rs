@@ -0,0 +1,214 @@
+//! Bringing colours from wider spaces into an RGB gamut, following the gamut
+//! mapping algorithm of CSS Color Level 4.
+
+use crate::colour::{
+    convert::{self, Vec3},
+    CssColour, Oklab, Oklch, RgbSpace, Srgb, WhitePoint, WideRgb, Xyz, RGB,
+};
+
+/// How to bring a colour outside an RGB gamut inside it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum GamutMapping {
+    /// Clamps each channel to `0.0..=1.0`. Cheap, but can shift the hue and
+    /// flatten gradients where several channels clip at once.
+    Clip,
+    /// Moves the colour in linear light towards the grey of the same
+    /// luminance until it fits, which keeps its luminance and, roughly, its
+    /// hue. Colours brighter than white or darker than black become white or
+    /// black.
+    Scale,
+    /// The CSS Color Level 4 algorithm: lowers chroma in Oklch, keeping
+    /// lightness and hue, until clipping the result changes it by less than
+    /// a just noticeable difference.
+    #[default]
+    Css,
+}
+
+/// A deltaEOK difference that is just noticeable.
+const JND: f64 = 0.02;
+
+/// How closely the chroma search converges.
+const EPSILON: f64 = 0.0001;
+
+fn clip(rgb: Vec3) -> Vec3 {
+    rgb.map(|c| c.clamp(0.0, 1.0))
+}
+
+fn to_oklab<S: RgbSpace>(colour: WideRgb<S>) -> Oklab {
+    let xyz = colour.to_xyz(WhitePoint::D65).to_array();
+    Oklab::from_linear_srgb(convert::xyz_to_linear_srgb(xyz))
+}
+
+/// The colour's channels scaled so that the largest is ±1, or, where some
+/// are infinite, those as ±1 and the rest as 0.
+fn direction<S: RgbSpace>(colour: WideRgb<S>) -> WideRgb<S> {
+    let channels = colour.to_array();
+    let largest = channels.iter().fold(0.0_f64, |largest, c| largest.max(c.abs()));
+    WideRgb::from_array(channels.map(|c| {
+        if largest.is_infinite() {
+            if c.is_infinite() {
+                c.signum()
+            } else {
+                0.0
+            }
+        } else {
+            c / largest
+        }
+    }))
+}
+
+/// Infinitely bright if `lightness` is positive, and infinitely dark
+/// otherwise.
+fn beyond(lightness: f64) -> f64 {
+    if lightness > 0.0 {
+        f64::INFINITY
+    } else {
+        f64::NEG_INFINITY
+    }
+}
+
+/// The colour in Oklab, as the origin for [`GamutMapping::Css`].
+///
+/// A colour with channels so large that converting it overflows is far
+/// brighter than white or darker than black, but has no lightness or hue
+/// that can be kept. It is given an infinite lightness with the sign of its
+/// direction's, found from its channels scaled down, and so maps to white
+/// or black.
+pub(crate) fn oklab_origin<S: RgbSpace>(colour: WideRgb<S>) -> Oklab {
+    let lab = to_oklab(colour);
+    if lab.to_array().iter().all(|c| c.is_finite()) {
+        return lab;
+    }
+    Oklab::new(beyond(to_oklab(direction(colour)).l), 0.0, 0.0)
+}
+
+fn from_oklab<S: RgbSpace>(lab: Oklab) -> WideRgb<S> {
+    let xyz = convert::linear_srgb_to_xyz(lab.to_linear_srgb());
+    WideRgb::from_xyz(Xyz::from_array(xyz), WhitePoint::D65)
+}
+
+/// Scales linear-light channels towards the grey of luminance `y`, just far
+/// enough to bring them all within `0.0..=1.0`.
+fn scale(linear: Vec3, y: f64) -> Vec3 {
+    if y >= 1.0 {
+        return [1.0; 3];
+    }
+    if y <= 0.0 || y.is_nan() {
+        return [0.0; 3];
+    }
+    let k = linear.iter().fold(1.0_f64, |k, &c| {
+        if c > 1.0 {
+            k.min((1.0 - y) / (c - y))
+        } else if c < 0.0 {
+            k.min(y / (y - c))
+        } else {
+            k
+        }
+    });
+    linear.map(|c| y + (c - y) * k)
+}
+
+/// Maps the colour at `origin` into the gamut of `S`. A lightness that is
+/// not a number counts as 0, as CSS treats NaN.
+pub(crate) fn css<S: RgbSpace>(origin: Oklch) -> WideRgb<S> {
+    if origin.l >= 1.0 {
+        return WideRgb::new(1.0, 1.0, 1.0);
+    }
+    if origin.l <= 0.0 || origin.l.is_nan() {
+        return WideRgb::new(0.0, 0.0, 0.0);
+    }
+
+    let mut current = origin;
+    let clip_current = |current: Oklch| {
+        let clipped = WideRgb::<S>::from_array(clip(from_oklab::<S>(current.into()).to_array()));
+        let e = to_oklab(clipped).delta_e(&current.into());
+        (clipped, e)
+    };
+
+    let (mut clipped, e) = clip_current(current);
+    if e < JND {
+        return clipped;
+    }
+
+    let (mut min, mut max) = (0.0, origin.c);
+    let mut min_in_gamut = true;
+    while max - min > EPSILON {
+        let chroma = (min + max) / 2.0;
+        current.c = chroma;
+        if min_in_gamut && from_oklab::<S>(current.into()).in_gamut() {
+            min = chroma;
+            continue;
+        }
+        let (candidate, e) = clip_current(current);
+        clipped = candidate;
+        if e < JND {
+            if JND - e < EPSILON {
+                break;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+    clipped
+}
+
+impl<S: RgbSpace> WideRgb<S> {
+    /// Whether every channel lies within `0.0..=1.0`, that is, whether the
+    /// colour lies within the space's gamut.
+    pub fn in_gamut(&self) -> bool {
+        self.to_array().iter().all(|c| (0.0..=1.0).contains(c))
+    }
+
+    /// Brings the colour within the space's gamut with the given strategy.
+    /// Colours already inside are returned unchanged.
+    ///
+    /// Channels that are not a number count as 0, as CSS treats NaN.
+    /// Colours too far out to convert without overflowing become white or
+    /// black, by the sign of their luminance or lightness.
+    pub fn to_gamut(self, strategy: GamutMapping) -> Self {
+        let colour = Self::from_array(self.to_array().map(|c| if c.is_nan() { 0.0 } else { c }));
+        if colour.in_gamut() {
+            return colour;
+        }
+        match strategy {
+            GamutMapping::Clip => Self::from_array(clip(colour.to_array())),
+            GamutMapping::Scale => {
+                let mut y = colour.to_xyz(S::WHITE).y;
+                if !y.is_finite() {
+                    y = beyond(direction(colour).to_xyz(S::WHITE).y);
+                }
+                Self::from_linear(scale(colour.to_linear(), y))
+            }
+            GamutMapping::Css => css(oklab_origin(colour).into()),
+        }
+    }
+}
+
+impl CssColour {
+    /// Brings the colour within the sRGB gamut with the given strategy,
+    /// keeping its alpha.
+    ///
+    /// [`GamutMapping::Css`] starts from the colour as parsed, for colours
+    /// from `lab()`, `lch()`, `oklab()`, `oklch()` and `color()`, rather than
+    /// from their sRGB channels.
+    pub fn to_gamut(&self, strategy: GamutMapping) -> CssColour {
+        let srgb = WideRgb::<Srgb>::new(self.red, self.green, self.blue);
+        let mapped = match self.origin() {
+            Some(origin) if strategy == GamutMapping::Css && !srgb.in_gamut() => {
+                css::<Srgb>(origin.into())
+            }
+            _ => srgb.to_gamut(strategy),
+        };
+        let [red, green, blue] = mapped.to_array();
+        CssColour::new(red, green, blue, self.alpha)
+    }
+}
+
+/// Encodes linear-light sRGB as an [`RGB`], mapping anything outside the sRGB
+/// gamut with [`GamutMapping::Css`].
+pub(crate) fn linear_srgb_to_rgb(linear: Vec3) -> RGB {
+    let srgb = WideRgb::<Srgb>::from_linear(linear).to_gamut(GamutMapping::Css);
+    RGB::from_unit(srgb.to_array())
+}
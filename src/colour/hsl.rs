@@ -0,0 +1,55 @@
+//! The HSL colour model.
+
+use crate::colour::{convert, Hsv, RGB};
+
+/// A colour as hue, saturation and lightness.
+///
+/// `hue` is in degrees within `0.0..360.0`; `saturation` and `lightness` are
+/// in `0.0..=1.0`. Greys have no meaningful hue, and converting one from
+/// [`RGB`] gives a hue of zero.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Hsl {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+}
+
+impl Hsl {
+    pub fn new(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+
+    /// Whether the colour is a grey, so that its hue has no effect.
+    pub fn is_achromatic(&self) -> bool {
+        self.saturation == 0.0 || self.lightness == 0.0 || self.lightness == 1.0
+    }
+}
+
+impl From<RGB> for Hsl {
+    fn from(rgb: RGB) -> Self {
+        let [hue, saturation, lightness] = convert::srgb_to_hsl(rgb.to_unit());
+        Self::new(hue, saturation, lightness)
+    }
+}
+
+impl From<Hsl> for RGB {
+    fn from(hsl: Hsl) -> Self {
+        RGB::from_unit(convert::hsl_to_srgb(hsl.hue, hsl.saturation, hsl.lightness))
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(hsv: Hsv) -> Self {
+        let lightness = hsv.value * (1.0 - hsv.saturation / 2.0);
+        let saturation = if lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            (hsv.value - lightness) / lightness.min(1.0 - lightness)
+        };
+        Self::new(hsv.hue, saturation, lightness)
+    }
+}
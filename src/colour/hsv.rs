@@ -0,0 +1,61 @@
+//! The HSV colour model.
+
+use crate::colour::{convert, Hsl, RGB};
+
+/// A colour as hue, saturation and value.
+///
+/// `hue` is in degrees within `0.0..360.0`; `saturation` and `value` are in
+/// `0.0..=1.0`. Greys have no meaningful hue, and converting one from
+/// [`RGB`] gives a hue of zero.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Hsv {
+    pub hue: f64,
+    pub saturation: f64,
+    pub value: f64,
+}
+
+impl Hsv {
+    pub fn new(hue: f64, saturation: f64, value: f64) -> Self {
+        Self {
+            hue,
+            saturation,
+            value,
+        }
+    }
+
+    /// Whether the colour is a grey, so that its hue has no effect.
+    pub fn is_achromatic(&self) -> bool {
+        self.saturation == 0.0 || self.value == 0.0
+    }
+}
+
+impl From<RGB> for Hsv {
+    fn from(rgb: RGB) -> Self {
+        let (hue, max, chroma) = convert::hue_max_chroma(rgb.to_unit());
+        let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+        Self::new(hue, saturation, max)
+    }
+}
+
+impl From<Hsv> for RGB {
+    fn from(hsv: Hsv) -> Self {
+        let hue = hsv.hue.rem_euclid(360.0);
+        let f = |n: f64| {
+            let k = (n + hue / 60.0) % 6.0;
+            hsv.value - hsv.value * hsv.saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+        RGB::from_unit([f(5.0), f(3.0), f(1.0)])
+    }
+}
+
+impl From<Hsl> for Hsv {
+    fn from(hsl: Hsl) -> Self {
+        let value = hsl.lightness + hsl.saturation * hsl.lightness.min(1.0 - hsl.lightness);
+        let saturation = if value == 0.0 {
+            0.0
+        } else {
+            2.0 * (1.0 - hsl.lightness / value)
+        };
+        Self::new(hsl.hue, saturation, value)
+    }
+}
@@ -0,0 +1,126 @@
+//! The CIE Lab and LCh colour spaces.
+
+use crate::colour::{convert, WhitePoint, Xyz, RGB};
+
+/// A colour in CIE L\*a\*b\*.
+///
+/// `l` is lightness from `0.0` to `100.0`; `a` and `b` are the green–red
+/// and blue–yellow axes, roughly within `-125.0..=125.0` for real colours.
+/// Lab values only mean something relative to a white point. The `From`
+/// conversions use [`WhitePoint::D50`], as CSS does; [`Lab::from_rgb`] and
+/// [`Lab::to_rgb`] take any white point.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Lab {
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+
+    pub(crate) fn from_array([l, a, b]: convert::Vec3) -> Self {
+        Self::new(l, a, b)
+    }
+
+    pub(crate) fn to_array(self) -> convert::Vec3 {
+        [self.l, self.a, self.b]
+    }
+
+    /// Converts from sRGB to Lab relative to `white`, adapting from sRGB's
+    /// D65 white point where necessary.
+    pub fn from_rgb(rgb: RGB, white: WhitePoint) -> Self {
+        Xyz::from(rgb).adapt(WhitePoint::D65, white).to_lab(white)
+    }
+
+    /// Converts Lab relative to `white` to sRGB, mapping anything outside
+    /// the sRGB gamut into it.
+    pub fn to_rgb(self, white: WhitePoint) -> RGB {
+        Xyz::from_lab(self, white)
+            .adapt(white, WhitePoint::D65)
+            .into()
+    }
+
+    /// The CIE76 colour difference: the Euclidean distance between two
+    /// colours. A difference of about 2.3 is just noticeable.
+    pub fn delta_e(&self, other: &Lab) -> f64 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+}
+
+impl From<RGB> for Lab {
+    fn from(rgb: RGB) -> Self {
+        Self::from_rgb(rgb, WhitePoint::D50)
+    }
+}
+
+impl From<Lab> for RGB {
+    fn from(lab: Lab) -> Self {
+        lab.to_rgb(WhitePoint::D50)
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(lch: Lch) -> Self {
+        Self::from_array(convert::polar_to_rect(lch.to_array()))
+    }
+}
+
+/// A colour in CIE LCh: [`Lab`] in cylindrical form.
+///
+/// `l` is the same lightness as in Lab, `c` is chroma, the distance from
+/// the neutral axis, and `h` is the hue angle in degrees within
+/// `0.0..360.0`. Greys have a chroma of zero, or very nearly, and their
+/// hue has no effect.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Lch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Lch {
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Self { l, c, h }
+    }
+
+    pub(crate) fn from_array([l, c, h]: convert::Vec3) -> Self {
+        Self::new(l, c, h)
+    }
+
+    pub(crate) fn to_array(self) -> convert::Vec3 {
+        [self.l, self.c, self.h]
+    }
+
+    /// Converts from sRGB to LCh relative to `white`.
+    pub fn from_rgb(rgb: RGB, white: WhitePoint) -> Self {
+        Lab::from_rgb(rgb, white).into()
+    }
+
+    /// Converts LCh relative to `white` to sRGB, mapping anything outside
+    /// the sRGB gamut into it.
+    pub fn to_rgb(self, white: WhitePoint) -> RGB {
+        Lab::from(self).to_rgb(white)
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(lab: Lab) -> Self {
+        Self::from_array(convert::rect_to_polar(lab.to_array()))
+    }
+}
+
+impl From<RGB> for Lch {
+    fn from(rgb: RGB) -> Self {
+        Lab::from(rgb).into()
+    }
+}
+
+impl From<Lch> for RGB {
+    fn from(lch: Lch) -> Self {
+        Lab::from(lch).into()
+    }
+}
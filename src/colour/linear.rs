@@ -0,0 +1,119 @@
+//! Linear-light sRGB.
+
+use crate::colour::{
+    convert, gamut,
+    rgb::{normalise_weights, BlendError},
+    RGB,
+};
+
+use std::sync::OnceLock;
+
+/// A colour in linear-light sRGB: the sRGB primaries without the transfer
+/// function, so that each channel is proportional to the light emitted.
+///
+/// Mixing, scaling and adding light are only physically meaningful here.
+/// Channels are nominally in `0.0..=1.0`; values outside that range are kept
+/// and describe colours outside the sRGB gamut.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct LinearRgb {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+}
+
+impl LinearRgb {
+    pub fn new(red: f64, green: f64, blue: f64) -> Self {
+        Self { red, green, blue }
+    }
+
+    pub(crate) fn from_array([red, green, blue]: convert::Vec3) -> Self {
+        Self::new(red, green, blue)
+    }
+
+    pub(crate) fn to_array(self) -> convert::Vec3 {
+        [self.red, self.green, self.blue]
+    }
+
+    /// Decodes gamma-encoded sRGB channels in `0.0..=1.0` with the exact sRGB
+    /// transfer function (the EOTF).
+    pub fn from_srgb(srgb: [f64; 3]) -> Self {
+        Self::from_array(srgb.map(convert::srgb_to_linear))
+    }
+
+    /// Encodes back to gamma-encoded sRGB channels with the inverse transfer
+    /// function (the OETF), without clipping.
+    pub fn to_srgb(self) -> [f64; 3] {
+        self.to_array().map(convert::linear_to_srgb)
+    }
+
+    /// Mixes with `other` by `t`, where `0.0` gives `self` and `1.0` gives
+    /// `other`.
+    pub fn mix(&self, other: &LinearRgb, t: f64) -> LinearRgb {
+        let (a, b) = (self.to_array(), other.to_array());
+        Self::from_array([0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t))
+    }
+}
+
+impl std::ops::Add for LinearRgb {
+    type Output = LinearRgb;
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.red + other.red, self.green + other.green, self.blue + other.blue)
+    }
+}
+
+/// Scales the amount of light, which unlike scaling encoded sRGB keeps the
+/// colour's chromaticity.
+impl std::ops::Mul<f64> for LinearRgb {
+    type Output = LinearRgb;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from_array(self.to_array().map(|c| c * rhs))
+    }
+}
+
+/// The linear value of every 8-bit sRGB channel, decoded once.
+fn decode_table() -> &'static [f64; 256] {
+    static TABLE: OnceLock<[f64; 256]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [0.0; 256];
+        for (c, value) in table.iter_mut().enumerate() {
+            *value = convert::srgb_to_linear(c as f64 / 255.0);
+        }
+        table
+    })
+}
+
+impl From<RGB> for LinearRgb {
+    /// Decodes through a lookup table, which gives the same values as the
+    /// transfer function since 8-bit channels have only 256 of them.
+    fn from(rgb: RGB) -> Self {
+        let (r, g, b) = rgb.to_tuple();
+        let table = decode_table();
+        Self::new(table[r as usize], table[g as usize], table[b as usize])
+    }
+}
+
+impl From<LinearRgb> for RGB {
+    /// Encodes to sRGB, mapping anything outside the sRGB gamut into it with
+    /// [`GamutMapping::Css`](crate::colour::GamutMapping::Css).
+    fn from(linear: LinearRgb) -> Self {
+        gamut::linear_srgb_to_rgb(linear.to_array())
+    }
+}
+
+impl RGB {
+    /// Like [`RGB::blend`], but mixes in linear light: both colours are
+    /// decoded, weighted and re-encoded. This keeps the mix as bright as the
+    /// light it represents, where mixing the encoded bytes comes out too
+    /// dark.
+    pub fn blend_linear(&self, other: Self, alpha: f64, beta: f64) -> Result<Self, BlendError> {
+        let (alpha, beta) = normalise_weights(alpha, beta)?;
+        Ok(RGB::from(LinearRgb::from(*self) * alpha + LinearRgb::from(other) * beta))
+    }
+
+    /// Like [`RGB::mix`], but mixes in linear light.
+    pub fn mix_linear(&self, other: Self, t: f64) -> Self {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        self.blend_linear(other, 1.0 - t, t)
+            .expect("weights from a clamped t are always valid")
+    }
+}
@@ -0,0 +1,33 @@
+//! Colour types.
+
+mod component;
+mod composite;
+pub(crate) mod convert;
+mod css;
+mod gamut;
+mod hsl;
+mod hsv;
+mod lab;
+mod linear;
+mod named;
+mod oklab;
+mod parse;
+mod rgb;
+mod rgb_space;
+mod rgba;
+mod xyz;
+
+pub use component::Component;
+pub use composite::{BlendMode, PorterDuff};
+pub use css::{parse_css_colour, CssColour};
+pub use gamut::GamutMapping;
+pub use hsl::Hsl;
+pub use hsv::Hsv;
+pub use lab::{Lab, Lch};
+pub use linear::LinearRgb;
+pub use oklab::{Oklab, Oklch};
+pub use parse::ColourParseError;
+pub use rgb::{BlendError, Rgb, BLEND_TOLERANCE, RGB};
+pub use rgb_space::{AcesCg, AdobeRgb, DisplayP3, ProPhotoRgb, Rec2020, RgbSpace, Srgb, WideRgb};
+pub use rgba::{PremultipliedRgba, Rgba};
+pub use xyz::{WhitePoint, Xyz};
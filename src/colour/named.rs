@@ -0,0 +1,896 @@
+//! Named colours.
+//!
+//! The CSS table holds all 148 CSS named colours, including the `grey`
+//! spellings. The X11 table, behind the `x11` feature, holds every entry of
+//! the X.Org `rgb.txt`, with names lowercased and spaces removed.
+
+use crate::colour::{Oklab, RGB};
+
+/// The CSS named colours, sorted by name.
+static CSS_COLOURS: &[(&str, RGB)] = &[
+    ("aliceblue", RGB::new(0xf0, 0xf8, 0xff)),
+    ("antiquewhite", RGB::new(0xfa, 0xeb, 0xd7)),
+    ("aqua", RGB::new(0x00, 0xff, 0xff)),
+    ("aquamarine", RGB::new(0x7f, 0xff, 0xd4)),
+    ("azure", RGB::new(0xf0, 0xff, 0xff)),
+    ("beige", RGB::new(0xf5, 0xf5, 0xdc)),
+    ("bisque", RGB::new(0xff, 0xe4, 0xc4)),
+    ("black", RGB::new(0x00, 0x00, 0x00)),
+    ("blanchedalmond", RGB::new(0xff, 0xeb, 0xcd)),
+    ("blue", RGB::new(0x00, 0x00, 0xff)),
+    ("blueviolet", RGB::new(0x8a, 0x2b, 0xe2)),
+    ("brown", RGB::new(0xa5, 0x2a, 0x2a)),
+    ("burlywood", RGB::new(0xde, 0xb8, 0x87)),
+    ("cadetblue", RGB::new(0x5f, 0x9e, 0xa0)),
+    ("chartreuse", RGB::new(0x7f, 0xff, 0x00)),
+    ("chocolate", RGB::new(0xd2, 0x69, 0x1e)),
+    ("coral", RGB::new(0xff, 0x7f, 0x50)),
+    ("cornflowerblue", RGB::new(0x64, 0x95, 0xed)),
+    ("cornsilk", RGB::new(0xff, 0xf8, 0xdc)),
+    ("crimson", RGB::new(0xdc, 0x14, 0x3c)),
+    ("cyan", RGB::new(0x00, 0xff, 0xff)),
+    ("darkblue", RGB::new(0x00, 0x00, 0x8b)),
+    ("darkcyan", RGB::new(0x00, 0x8b, 0x8b)),
+    ("darkgoldenrod", RGB::new(0xb8, 0x86, 0x0b)),
+    ("darkgray", RGB::new(0xa9, 0xa9, 0xa9)),
+    ("darkgreen", RGB::new(0x00, 0x64, 0x00)),
+    ("darkgrey", RGB::new(0xa9, 0xa9, 0xa9)),
+    ("darkkhaki", RGB::new(0xbd, 0xb7, 0x6b)),
+    ("darkmagenta", RGB::new(0x8b, 0x00, 0x8b)),
+    ("darkolivegreen", RGB::new(0x55, 0x6b, 0x2f)),
+    ("darkorange", RGB::new(0xff, 0x8c, 0x00)),
+    ("darkorchid", RGB::new(0x99, 0x32, 0xcc)),
+    ("darkred", RGB::new(0x8b, 0x00, 0x00)),
+    ("darksalmon", RGB::new(0xe9, 0x96, 0x7a)),
+    ("darkseagreen", RGB::new(0x8f, 0xbc, 0x8f)),
+    ("darkslateblue", RGB::new(0x48, 0x3d, 0x8b)),
+    ("darkslategray", RGB::new(0x2f, 0x4f, 0x4f)),
+    ("darkslategrey", RGB::new(0x2f, 0x4f, 0x4f)),
+    ("darkturquoise", RGB::new(0x00, 0xce, 0xd1)),
+    ("darkviolet", RGB::new(0x94, 0x00, 0xd3)),
+    ("deeppink", RGB::new(0xff, 0x14, 0x93)),
+    ("deepskyblue", RGB::new(0x00, 0xbf, 0xff)),
+    ("dimgray", RGB::new(0x69, 0x69, 0x69)),
+    ("dimgrey", RGB::new(0x69, 0x69, 0x69)),
+    ("dodgerblue", RGB::new(0x1e, 0x90, 0xff)),
+    ("firebrick", RGB::new(0xb2, 0x22, 0x22)),
+    ("floralwhite", RGB::new(0xff, 0xfa, 0xf0)),
+    ("forestgreen", RGB::new(0x22, 0x8b, 0x22)),
+    ("fuchsia", RGB::new(0xff, 0x00, 0xff)),
+    ("gainsboro", RGB::new(0xdc, 0xdc, 0xdc)),
+    ("ghostwhite", RGB::new(0xf8, 0xf8, 0xff)),
+    ("gold", RGB::new(0xff, 0xd7, 0x00)),
+    ("goldenrod", RGB::new(0xda, 0xa5, 0x20)),
+    ("gray", RGB::new(0x80, 0x80, 0x80)),
+    ("green", RGB::new(0x00, 0x80, 0x00)),
+    ("greenyellow", RGB::new(0xad, 0xff, 0x2f)),
+    ("grey", RGB::new(0x80, 0x80, 0x80)),
+    ("honeydew", RGB::new(0xf0, 0xff, 0xf0)),
+    ("hotpink", RGB::new(0xff, 0x69, 0xb4)),
+    ("indianred", RGB::new(0xcd, 0x5c, 0x5c)),
+    ("indigo", RGB::new(0x4b, 0x00, 0x82)),
+    ("ivory", RGB::new(0xff, 0xff, 0xf0)),
+    ("khaki", RGB::new(0xf0, 0xe6, 0x8c)),
+    ("lavender", RGB::new(0xe6, 0xe6, 0xfa)),
+    ("lavenderblush", RGB::new(0xff, 0xf0, 0xf5)),
+    ("lawngreen", RGB::new(0x7c, 0xfc, 0x00)),
+    ("lemonchiffon", RGB::new(0xff, 0xfa, 0xcd)),
+    ("lightblue", RGB::new(0xad, 0xd8, 0xe6)),
+    ("lightcoral", RGB::new(0xf0, 0x80, 0x80)),
+    ("lightcyan", RGB::new(0xe0, 0xff, 0xff)),
+    ("lightgoldenrodyellow", RGB::new(0xfa, 0xfa, 0xd2)),
+    ("lightgray", RGB::new(0xd3, 0xd3, 0xd3)),
+    ("lightgreen", RGB::new(0x90, 0xee, 0x90)),
+    ("lightgrey", RGB::new(0xd3, 0xd3, 0xd3)),
+    ("lightpink", RGB::new(0xff, 0xb6, 0xc1)),
+    ("lightsalmon", RGB::new(0xff, 0xa0, 0x7a)),
+    ("lightseagreen", RGB::new(0x20, 0xb2, 0xaa)),
+    ("lightskyblue", RGB::new(0x87, 0xce, 0xfa)),
+    ("lightslategray", RGB::new(0x77, 0x88, 0x99)),
+    ("lightslategrey", RGB::new(0x77, 0x88, 0x99)),
+    ("lightsteelblue", RGB::new(0xb0, 0xc4, 0xde)),
+    ("lightyellow", RGB::new(0xff, 0xff, 0xe0)),
+    ("lime", RGB::new(0x00, 0xff, 0x00)),
+    ("limegreen", RGB::new(0x32, 0xcd, 0x32)),
+    ("linen", RGB::new(0xfa, 0xf0, 0xe6)),
+    ("magenta", RGB::new(0xff, 0x00, 0xff)),
+    ("maroon", RGB::new(0x80, 0x00, 0x00)),
+    ("mediumaquamarine", RGB::new(0x66, 0xcd, 0xaa)),
+    ("mediumblue", RGB::new(0x00, 0x00, 0xcd)),
+    ("mediumorchid", RGB::new(0xba, 0x55, 0xd3)),
+    ("mediumpurple", RGB::new(0x93, 0x70, 0xdb)),
+    ("mediumseagreen", RGB::new(0x3c, 0xb3, 0x71)),
+    ("mediumslateblue", RGB::new(0x7b, 0x68, 0xee)),
+    ("mediumspringgreen", RGB::new(0x00, 0xfa, 0x9a)),
+    ("mediumturquoise", RGB::new(0x48, 0xd1, 0xcc)),
+    ("mediumvioletred", RGB::new(0xc7, 0x15, 0x85)),
+    ("midnightblue", RGB::new(0x19, 0x19, 0x70)),
+    ("mintcream", RGB::new(0xf5, 0xff, 0xfa)),
+    ("mistyrose", RGB::new(0xff, 0xe4, 0xe1)),
+    ("moccasin", RGB::new(0xff, 0xe4, 0xb5)),
+    ("navajowhite", RGB::new(0xff, 0xde, 0xad)),
+    ("navy", RGB::new(0x00, 0x00, 0x80)),
+    ("oldlace", RGB::new(0xfd, 0xf5, 0xe6)),
+    ("olive", RGB::new(0x80, 0x80, 0x00)),
+    ("olivedrab", RGB::new(0x6b, 0x8e, 0x23)),
+    ("orange", RGB::new(0xff, 0xa5, 0x00)),
+    ("orangered", RGB::new(0xff, 0x45, 0x00)),
+    ("orchid", RGB::new(0xda, 0x70, 0xd6)),
+    ("palegoldenrod", RGB::new(0xee, 0xe8, 0xaa)),
+    ("palegreen", RGB::new(0x98, 0xfb, 0x98)),
+    ("paleturquoise", RGB::new(0xaf, 0xee, 0xee)),
+    ("palevioletred", RGB::new(0xdb, 0x70, 0x93)),
+    ("papayawhip", RGB::new(0xff, 0xef, 0xd5)),
+    ("peachpuff", RGB::new(0xff, 0xda, 0xb9)),
+    ("peru", RGB::new(0xcd, 0x85, 0x3f)),
+    ("pink", RGB::new(0xff, 0xc0, 0xcb)),
+    ("plum", RGB::new(0xdd, 0xa0, 0xdd)),
+    ("powderblue", RGB::new(0xb0, 0xe0, 0xe6)),
+    ("purple", RGB::new(0x80, 0x00, 0x80)),
+    ("rebeccapurple", RGB::new(0x66, 0x33, 0x99)),
+    ("red", RGB::new(0xff, 0x00, 0x00)),
+    ("rosybrown", RGB::new(0xbc, 0x8f, 0x8f)),
+    ("royalblue", RGB::new(0x41, 0x69, 0xe1)),
+    ("saddlebrown", RGB::new(0x8b, 0x45, 0x13)),
+    ("salmon", RGB::new(0xfa, 0x80, 0x72)),
+    ("sandybrown", RGB::new(0xf4, 0xa4, 0x60)),
+    ("seagreen", RGB::new(0x2e, 0x8b, 0x57)),
+    ("seashell", RGB::new(0xff, 0xf5, 0xee)),
+    ("sienna", RGB::new(0xa0, 0x52, 0x2d)),
+    ("silver", RGB::new(0xc0, 0xc0, 0xc0)),
+    ("skyblue", RGB::new(0x87, 0xce, 0xeb)),
+    ("slateblue", RGB::new(0x6a, 0x5a, 0xcd)),
+    ("slategray", RGB::new(0x70, 0x80, 0x90)),
+    ("slategrey", RGB::new(0x70, 0x80, 0x90)),
+    ("snow", RGB::new(0xff, 0xfa, 0xfa)),
+    ("springgreen", RGB::new(0x00, 0xff, 0x7f)),
+    ("steelblue", RGB::new(0x46, 0x82, 0xb4)),
+    ("tan", RGB::new(0xd2, 0xb4, 0x8c)),
+    ("teal", RGB::new(0x00, 0x80, 0x80)),
+    ("thistle", RGB::new(0xd8, 0xbf, 0xd8)),
+    ("tomato", RGB::new(0xff, 0x63, 0x47)),
+    ("turquoise", RGB::new(0x40, 0xe0, 0xd0)),
+    ("violet", RGB::new(0xee, 0x82, 0xee)),
+    ("wheat", RGB::new(0xf5, 0xde, 0xb3)),
+    ("white", RGB::new(0xff, 0xff, 0xff)),
+    ("whitesmoke", RGB::new(0xf5, 0xf5, 0xf5)),
+    ("yellow", RGB::new(0xff, 0xff, 0x00)),
+    ("yellowgreen", RGB::new(0x9a, 0xcd, 0x32)),
+];
+
+#[cfg(feature = "x11")]
+static X11_COLOURS: &[(&str, RGB)] = &[
+    ("aliceblue", RGB::new(0xf0, 0xf8, 0xff)),
+    ("antiquewhite", RGB::new(0xfa, 0xeb, 0xd7)),
+    ("antiquewhite1", RGB::new(0xff, 0xef, 0xdb)),
+    ("antiquewhite2", RGB::new(0xee, 0xdf, 0xcc)),
+    ("antiquewhite3", RGB::new(0xcd, 0xc0, 0xb0)),
+    ("antiquewhite4", RGB::new(0x8b, 0x83, 0x78)),
+    ("aquamarine", RGB::new(0x7f, 0xff, 0xd4)),
+    ("aquamarine1", RGB::new(0x7f, 0xff, 0xd4)),
+    ("aquamarine2", RGB::new(0x76, 0xee, 0xc6)),
+    ("aquamarine3", RGB::new(0x66, 0xcd, 0xaa)),
+    ("aquamarine4", RGB::new(0x45, 0x8b, 0x74)),
+    ("azure", RGB::new(0xf0, 0xff, 0xff)),
+    ("azure1", RGB::new(0xf0, 0xff, 0xff)),
+    ("azure2", RGB::new(0xe0, 0xee, 0xee)),
+    ("azure3", RGB::new(0xc1, 0xcd, 0xcd)),
+    ("azure4", RGB::new(0x83, 0x8b, 0x8b)),
+    ("beige", RGB::new(0xf5, 0xf5, 0xdc)),
+    ("bisque", RGB::new(0xff, 0xe4, 0xc4)),
+    ("bisque1", RGB::new(0xff, 0xe4, 0xc4)),
+    ("bisque2", RGB::new(0xee, 0xd5, 0xb7)),
+    ("bisque3", RGB::new(0xcd, 0xb7, 0x9e)),
+    ("bisque4", RGB::new(0x8b, 0x7d, 0x6b)),
+    ("black", RGB::new(0x00, 0x00, 0x00)),
+    ("blanchedalmond", RGB::new(0xff, 0xeb, 0xcd)),
+    ("blue", RGB::new(0x00, 0x00, 0xff)),
+    ("blue1", RGB::new(0x00, 0x00, 0xff)),
+    ("blue2", RGB::new(0x00, 0x00, 0xee)),
+    ("blue3", RGB::new(0x00, 0x00, 0xcd)),
+    ("blue4", RGB::new(0x00, 0x00, 0x8b)),
+    ("blueviolet", RGB::new(0x8a, 0x2b, 0xe2)),
+    ("brown", RGB::new(0xa5, 0x2a, 0x2a)),
+    ("brown1", RGB::new(0xff, 0x40, 0x40)),
+    ("brown2", RGB::new(0xee, 0x3b, 0x3b)),
+    ("brown3", RGB::new(0xcd, 0x33, 0x33)),
+    ("brown4", RGB::new(0x8b, 0x23, 0x23)),
+    ("burlywood", RGB::new(0xde, 0xb8, 0x87)),
+    ("burlywood1", RGB::new(0xff, 0xd3, 0x9b)),
+    ("burlywood2", RGB::new(0xee, 0xc5, 0x91)),
+    ("burlywood3", RGB::new(0xcd, 0xaa, 0x7d)),
+    ("burlywood4", RGB::new(0x8b, 0x73, 0x55)),
+    ("cadetblue", RGB::new(0x5f, 0x9e, 0xa0)),
+    ("cadetblue1", RGB::new(0x98, 0xf5, 0xff)),
+    ("cadetblue2", RGB::new(0x8e, 0xe5, 0xee)),
+    ("cadetblue3", RGB::new(0x7a, 0xc5, 0xcd)),
+    ("cadetblue4", RGB::new(0x53, 0x86, 0x8b)),
+    ("chartreuse", RGB::new(0x7f, 0xff, 0x00)),
+    ("chartreuse1", RGB::new(0x7f, 0xff, 0x00)),
+    ("chartreuse2", RGB::new(0x76, 0xee, 0x00)),
+    ("chartreuse3", RGB::new(0x66, 0xcd, 0x00)),
+    ("chartreuse4", RGB::new(0x45, 0x8b, 0x00)),
+    ("chocolate", RGB::new(0xd2, 0x69, 0x1e)),
+    ("chocolate1", RGB::new(0xff, 0x7f, 0x24)),
+    ("chocolate2", RGB::new(0xee, 0x76, 0x21)),
+    ("chocolate3", RGB::new(0xcd, 0x66, 0x1d)),
+    ("chocolate4", RGB::new(0x8b, 0x45, 0x13)),
+    ("coral", RGB::new(0xff, 0x7f, 0x50)),
+    ("coral1", RGB::new(0xff, 0x72, 0x56)),
+    ("coral2", RGB::new(0xee, 0x6a, 0x50)),
+    ("coral3", RGB::new(0xcd, 0x5b, 0x45)),
+    ("coral4", RGB::new(0x8b, 0x3e, 0x2f)),
+    ("cornflowerblue", RGB::new(0x64, 0x95, 0xed)),
+    ("cornsilk", RGB::new(0xff, 0xf8, 0xdc)),
+    ("cornsilk1", RGB::new(0xff, 0xf8, 0xdc)),
+    ("cornsilk2", RGB::new(0xee, 0xe8, 0xcd)),
+    ("cornsilk3", RGB::new(0xcd, 0xc8, 0xb1)),
+    ("cornsilk4", RGB::new(0x8b, 0x88, 0x78)),
+    ("cyan", RGB::new(0x00, 0xff, 0xff)),
+    ("cyan1", RGB::new(0x00, 0xff, 0xff)),
+    ("cyan2", RGB::new(0x00, 0xee, 0xee)),
+    ("cyan3", RGB::new(0x00, 0xcd, 0xcd)),
+    ("cyan4", RGB::new(0x00, 0x8b, 0x8b)),
+    ("darkblue", RGB::new(0x00, 0x00, 0x8b)),
+    ("darkcyan", RGB::new(0x00, 0x8b, 0x8b)),
+    ("darkgoldenrod", RGB::new(0xb8, 0x86, 0x0b)),
+    ("darkgoldenrod1", RGB::new(0xff, 0xb9, 0x0f)),
+    ("darkgoldenrod2", RGB::new(0xee, 0xad, 0x0e)),
+    ("darkgoldenrod3", RGB::new(0xcd, 0x95, 0x0c)),
+    ("darkgoldenrod4", RGB::new(0x8b, 0x65, 0x08)),
+    ("darkgray", RGB::new(0xa9, 0xa9, 0xa9)),
+    ("darkgreen", RGB::new(0x00, 0x64, 0x00)),
+    ("darkgrey", RGB::new(0xa9, 0xa9, 0xa9)),
+    ("darkkhaki", RGB::new(0xbd, 0xb7, 0x6b)),
+    ("darkmagenta", RGB::new(0x8b, 0x00, 0x8b)),
+    ("darkolivegreen", RGB::new(0x55, 0x6b, 0x2f)),
+    ("darkolivegreen1", RGB::new(0xca, 0xff, 0x70)),
+    ("darkolivegreen2", RGB::new(0xbc, 0xee, 0x68)),
+    ("darkolivegreen3", RGB::new(0xa2, 0xcd, 0x5a)),
+    ("darkolivegreen4", RGB::new(0x6e, 0x8b, 0x3d)),
+    ("darkorange", RGB::new(0xff, 0x8c, 0x00)),
+    ("darkorange1", RGB::new(0xff, 0x7f, 0x00)),
+    ("darkorange2", RGB::new(0xee, 0x76, 0x00)),
+    ("darkorange3", RGB::new(0xcd, 0x66, 0x00)),
+    ("darkorange4", RGB::new(0x8b, 0x45, 0x00)),
+    ("darkorchid", RGB::new(0x99, 0x32, 0xcc)),
+    ("darkorchid1", RGB::new(0xbf, 0x3e, 0xff)),
+    ("darkorchid2", RGB::new(0xb2, 0x3a, 0xee)),
+    ("darkorchid3", RGB::new(0x9a, 0x32, 0xcd)),
+    ("darkorchid4", RGB::new(0x68, 0x22, 0x8b)),
+    ("darkred", RGB::new(0x8b, 0x00, 0x00)),
+    ("darksalmon", RGB::new(0xe9, 0x96, 0x7a)),
+    ("darkseagreen", RGB::new(0x8f, 0xbc, 0x8f)),
+    ("darkseagreen1", RGB::new(0xc1, 0xff, 0xc1)),
+    ("darkseagreen2", RGB::new(0xb4, 0xee, 0xb4)),
+    ("darkseagreen3", RGB::new(0x9b, 0xcd, 0x9b)),
+    ("darkseagreen4", RGB::new(0x69, 0x8b, 0x69)),
+    ("darkslateblue", RGB::new(0x48, 0x3d, 0x8b)),
+    ("darkslategray", RGB::new(0x2f, 0x4f, 0x4f)),
+    ("darkslategray1", RGB::new(0x97, 0xff, 0xff)),
+    ("darkslategray2", RGB::new(0x8d, 0xee, 0xee)),
+    ("darkslategray3", RGB::new(0x79, 0xcd, 0xcd)),
+    ("darkslategray4", RGB::new(0x52, 0x8b, 0x8b)),
+    ("darkslategrey", RGB::new(0x2f, 0x4f, 0x4f)),
+    ("darkturquoise", RGB::new(0x00, 0xce, 0xd1)),
+    ("darkviolet", RGB::new(0x94, 0x00, 0xd3)),
+    ("debianred", RGB::new(0xd7, 0x07, 0x51)),
+    ("deeppink", RGB::new(0xff, 0x14, 0x93)),
+    ("deeppink1", RGB::new(0xff, 0x14, 0x93)),
+    ("deeppink2", RGB::new(0xee, 0x12, 0x89)),
+    ("deeppink3", RGB::new(0xcd, 0x10, 0x76)),
+    ("deeppink4", RGB::new(0x8b, 0x0a, 0x50)),
+    ("deepskyblue", RGB::new(0x00, 0xbf, 0xff)),
+    ("deepskyblue1", RGB::new(0x00, 0xbf, 0xff)),
+    ("deepskyblue2", RGB::new(0x00, 0xb2, 0xee)),
+    ("deepskyblue3", RGB::new(0x00, 0x9a, 0xcd)),
+    ("deepskyblue4", RGB::new(0x00, 0x68, 0x8b)),
+    ("dimgray", RGB::new(0x69, 0x69, 0x69)),
+    ("dimgrey", RGB::new(0x69, 0x69, 0x69)),
+    ("dodgerblue", RGB::new(0x1e, 0x90, 0xff)),
+    ("dodgerblue1", RGB::new(0x1e, 0x90, 0xff)),
+    ("dodgerblue2", RGB::new(0x1c, 0x86, 0xee)),
+    ("dodgerblue3", RGB::new(0x18, 0x74, 0xcd)),
+    ("dodgerblue4", RGB::new(0x10, 0x4e, 0x8b)),
+    ("firebrick", RGB::new(0xb2, 0x22, 0x22)),
+    ("firebrick1", RGB::new(0xff, 0x30, 0x30)),
+    ("firebrick2", RGB::new(0xee, 0x2c, 0x2c)),
+    ("firebrick3", RGB::new(0xcd, 0x26, 0x26)),
+    ("firebrick4", RGB::new(0x8b, 0x1a, 0x1a)),
+    ("floralwhite", RGB::new(0xff, 0xfa, 0xf0)),
+    ("forestgreen", RGB::new(0x22, 0x8b, 0x22)),
+    ("gainsboro", RGB::new(0xdc, 0xdc, 0xdc)),
+    ("ghostwhite", RGB::new(0xf8, 0xf8, 0xff)),
+    ("gold", RGB::new(0xff, 0xd7, 0x00)),
+    ("gold1", RGB::new(0xff, 0xd7, 0x00)),
+    ("gold2", RGB::new(0xee, 0xc9, 0x00)),
+    ("gold3", RGB::new(0xcd, 0xad, 0x00)),
+    ("gold4", RGB::new(0x8b, 0x75, 0x00)),
+    ("goldenrod", RGB::new(0xda, 0xa5, 0x20)),
+    ("goldenrod1", RGB::new(0xff, 0xc1, 0x25)),
+    ("goldenrod2", RGB::new(0xee, 0xb4, 0x22)),
+    ("goldenrod3", RGB::new(0xcd, 0x9b, 0x1d)),
+    ("goldenrod4", RGB::new(0x8b, 0x69, 0x14)),
+    ("gray", RGB::new(0xbe, 0xbe, 0xbe)),
+    ("gray0", RGB::new(0x00, 0x00, 0x00)),
+    ("gray1", RGB::new(0x03, 0x03, 0x03)),
+    ("gray10", RGB::new(0x1a, 0x1a, 0x1a)),
+    ("gray100", RGB::new(0xff, 0xff, 0xff)),
+    ("gray11", RGB::new(0x1c, 0x1c, 0x1c)),
+    ("gray12", RGB::new(0x1f, 0x1f, 0x1f)),
+    ("gray13", RGB::new(0x21, 0x21, 0x21)),
+    ("gray14", RGB::new(0x24, 0x24, 0x24)),
+    ("gray15", RGB::new(0x26, 0x26, 0x26)),
+    ("gray16", RGB::new(0x29, 0x29, 0x29)),
+    ("gray17", RGB::new(0x2b, 0x2b, 0x2b)),
+    ("gray18", RGB::new(0x2e, 0x2e, 0x2e)),
+    ("gray19", RGB::new(0x30, 0x30, 0x30)),
+    ("gray2", RGB::new(0x05, 0x05, 0x05)),
+    ("gray20", RGB::new(0x33, 0x33, 0x33)),
+    ("gray21", RGB::new(0x36, 0x36, 0x36)),
+    ("gray22", RGB::new(0x38, 0x38, 0x38)),
+    ("gray23", RGB::new(0x3b, 0x3b, 0x3b)),
+    ("gray24", RGB::new(0x3d, 0x3d, 0x3d)),
+    ("gray25", RGB::new(0x40, 0x40, 0x40)),
+    ("gray26", RGB::new(0x42, 0x42, 0x42)),
+    ("gray27", RGB::new(0x45, 0x45, 0x45)),
+    ("gray28", RGB::new(0x47, 0x47, 0x47)),
+    ("gray29", RGB::new(0x4a, 0x4a, 0x4a)),
+    ("gray3", RGB::new(0x08, 0x08, 0x08)),
+    ("gray30", RGB::new(0x4d, 0x4d, 0x4d)),
+    ("gray31", RGB::new(0x4f, 0x4f, 0x4f)),
+    ("gray32", RGB::new(0x52, 0x52, 0x52)),
+    ("gray33", RGB::new(0x54, 0x54, 0x54)),
+    ("gray34", RGB::new(0x57, 0x57, 0x57)),
+    ("gray35", RGB::new(0x59, 0x59, 0x59)),
+    ("gray36", RGB::new(0x5c, 0x5c, 0x5c)),
+    ("gray37", RGB::new(0x5e, 0x5e, 0x5e)),
+    ("gray38", RGB::new(0x61, 0x61, 0x61)),
+    ("gray39", RGB::new(0x63, 0x63, 0x63)),
+    ("gray4", RGB::new(0x0a, 0x0a, 0x0a)),
+    ("gray40", RGB::new(0x66, 0x66, 0x66)),
+    ("gray41", RGB::new(0x69, 0x69, 0x69)),
+    ("gray42", RGB::new(0x6b, 0x6b, 0x6b)),
+    ("gray43", RGB::new(0x6e, 0x6e, 0x6e)),
+    ("gray44", RGB::new(0x70, 0x70, 0x70)),
+    ("gray45", RGB::new(0x73, 0x73, 0x73)),
+    ("gray46", RGB::new(0x75, 0x75, 0x75)),
+    ("gray47", RGB::new(0x78, 0x78, 0x78)),
+    ("gray48", RGB::new(0x7a, 0x7a, 0x7a)),
+    ("gray49", RGB::new(0x7d, 0x7d, 0x7d)),
+    ("gray5", RGB::new(0x0d, 0x0d, 0x0d)),
+    ("gray50", RGB::new(0x7f, 0x7f, 0x7f)),
+    ("gray51", RGB::new(0x82, 0x82, 0x82)),
+    ("gray52", RGB::new(0x85, 0x85, 0x85)),
+    ("gray53", RGB::new(0x87, 0x87, 0x87)),
+    ("gray54", RGB::new(0x8a, 0x8a, 0x8a)),
+    ("gray55", RGB::new(0x8c, 0x8c, 0x8c)),
+    ("gray56", RGB::new(0x8f, 0x8f, 0x8f)),
+    ("gray57", RGB::new(0x91, 0x91, 0x91)),
+    ("gray58", RGB::new(0x94, 0x94, 0x94)),
+    ("gray59", RGB::new(0x96, 0x96, 0x96)),
+    ("gray6", RGB::new(0x0f, 0x0f, 0x0f)),
+    ("gray60", RGB::new(0x99, 0x99, 0x99)),
+    ("gray61", RGB::new(0x9c, 0x9c, 0x9c)),
+    ("gray62", RGB::new(0x9e, 0x9e, 0x9e)),
+    ("gray63", RGB::new(0xa1, 0xa1, 0xa1)),
+    ("gray64", RGB::new(0xa3, 0xa3, 0xa3)),
+    ("gray65", RGB::new(0xa6, 0xa6, 0xa6)),
+    ("gray66", RGB::new(0xa8, 0xa8, 0xa8)),
+    ("gray67", RGB::new(0xab, 0xab, 0xab)),
+    ("gray68", RGB::new(0xad, 0xad, 0xad)),
+    ("gray69", RGB::new(0xb0, 0xb0, 0xb0)),
+    ("gray7", RGB::new(0x12, 0x12, 0x12)),
+    ("gray70", RGB::new(0xb3, 0xb3, 0xb3)),
+    ("gray71", RGB::new(0xb5, 0xb5, 0xb5)),
+    ("gray72", RGB::new(0xb8, 0xb8, 0xb8)),
+    ("gray73", RGB::new(0xba, 0xba, 0xba)),
+    ("gray74", RGB::new(0xbd, 0xbd, 0xbd)),
+    ("gray75", RGB::new(0xbf, 0xbf, 0xbf)),
+    ("gray76", RGB::new(0xc2, 0xc2, 0xc2)),
+    ("gray77", RGB::new(0xc4, 0xc4, 0xc4)),
+    ("gray78", RGB::new(0xc7, 0xc7, 0xc7)),
+    ("gray79", RGB::new(0xc9, 0xc9, 0xc9)),
+    ("gray8", RGB::new(0x14, 0x14, 0x14)),
+    ("gray80", RGB::new(0xcc, 0xcc, 0xcc)),
+    ("gray81", RGB::new(0xcf, 0xcf, 0xcf)),
+    ("gray82", RGB::new(0xd1, 0xd1, 0xd1)),
+    ("gray83", RGB::new(0xd4, 0xd4, 0xd4)),
+    ("gray84", RGB::new(0xd6, 0xd6, 0xd6)),
+    ("gray85", RGB::new(0xd9, 0xd9, 0xd9)),
+    ("gray86", RGB::new(0xdb, 0xdb, 0xdb)),
+    ("gray87", RGB::new(0xde, 0xde, 0xde)),
+    ("gray88", RGB::new(0xe0, 0xe0, 0xe0)),
+    ("gray89", RGB::new(0xe3, 0xe3, 0xe3)),
+    ("gray9", RGB::new(0x17, 0x17, 0x17)),
+    ("gray90", RGB::new(0xe5, 0xe5, 0xe5)),
+    ("gray91", RGB::new(0xe8, 0xe8, 0xe8)),
+    ("gray92", RGB::new(0xeb, 0xeb, 0xeb)),
+    ("gray93", RGB::new(0xed, 0xed, 0xed)),
+    ("gray94", RGB::new(0xf0, 0xf0, 0xf0)),
+    ("gray95", RGB::new(0xf2, 0xf2, 0xf2)),
+    ("gray96", RGB::new(0xf5, 0xf5, 0xf5)),
+    ("gray97", RGB::new(0xf7, 0xf7, 0xf7)),
+    ("gray98", RGB::new(0xfa, 0xfa, 0xfa)),
+    ("gray99", RGB::new(0xfc, 0xfc, 0xfc)),
+    ("green", RGB::new(0x00, 0xff, 0x00)),
+    ("green1", RGB::new(0x00, 0xff, 0x00)),
+    ("green2", RGB::new(0x00, 0xee, 0x00)),
+    ("green3", RGB::new(0x00, 0xcd, 0x00)),
+    ("green4", RGB::new(0x00, 0x8b, 0x00)),
+    ("greenyellow", RGB::new(0xad, 0xff, 0x2f)),
+    ("grey", RGB::new(0xbe, 0xbe, 0xbe)),
+    ("grey0", RGB::new(0x00, 0x00, 0x00)),
+    ("grey1", RGB::new(0x03, 0x03, 0x03)),
+    ("grey10", RGB::new(0x1a, 0x1a, 0x1a)),
+    ("grey100", RGB::new(0xff, 0xff, 0xff)),
+    ("grey11", RGB::new(0x1c, 0x1c, 0x1c)),
+    ("grey12", RGB::new(0x1f, 0x1f, 0x1f)),
+    ("grey13", RGB::new(0x21, 0x21, 0x21)),
+    ("grey14", RGB::new(0x24, 0x24, 0x24)),
+    ("grey15", RGB::new(0x26, 0x26, 0x26)),
+    ("grey16", RGB::new(0x29, 0x29, 0x29)),
+    ("grey17", RGB::new(0x2b, 0x2b, 0x2b)),
+    ("grey18", RGB::new(0x2e, 0x2e, 0x2e)),
+    ("grey19", RGB::new(0x30, 0x30, 0x30)),
+    ("grey2", RGB::new(0x05, 0x05, 0x05)),
+    ("grey20", RGB::new(0x33, 0x33, 0x33)),
+    ("grey21", RGB::new(0x36, 0x36, 0x36)),
+    ("grey22", RGB::new(0x38, 0x38, 0x38)),
+    ("grey23", RGB::new(0x3b, 0x3b, 0x3b)),
+    ("grey24", RGB::new(0x3d, 0x3d, 0x3d)),
+    ("grey25", RGB::new(0x40, 0x40, 0x40)),
+    ("grey26", RGB::new(0x42, 0x42, 0x42)),
+    ("grey27", RGB::new(0x45, 0x45, 0x45)),
+    ("grey28", RGB::new(0x47, 0x47, 0x47)),
+    ("grey29", RGB::new(0x4a, 0x4a, 0x4a)),
+    ("grey3", RGB::new(0x08, 0x08, 0x08)),
+    ("grey30", RGB::new(0x4d, 0x4d, 0x4d)),
+    ("grey31", RGB::new(0x4f, 0x4f, 0x4f)),
+    ("grey32", RGB::new(0x52, 0x52, 0x52)),
+    ("grey33", RGB::new(0x54, 0x54, 0x54)),
+    ("grey34", RGB::new(0x57, 0x57, 0x57)),
+    ("grey35", RGB::new(0x59, 0x59, 0x59)),
+    ("grey36", RGB::new(0x5c, 0x5c, 0x5c)),
+    ("grey37", RGB::new(0x5e, 0x5e, 0x5e)),
+    ("grey38", RGB::new(0x61, 0x61, 0x61)),
+    ("grey39", RGB::new(0x63, 0x63, 0x63)),
+    ("grey4", RGB::new(0x0a, 0x0a, 0x0a)),
+    ("grey40", RGB::new(0x66, 0x66, 0x66)),
+    ("grey41", RGB::new(0x69, 0x69, 0x69)),
+    ("grey42", RGB::new(0x6b, 0x6b, 0x6b)),
+    ("grey43", RGB::new(0x6e, 0x6e, 0x6e)),
+    ("grey44", RGB::new(0x70, 0x70, 0x70)),
+    ("grey45", RGB::new(0x73, 0x73, 0x73)),
+    ("grey46", RGB::new(0x75, 0x75, 0x75)),
+    ("grey47", RGB::new(0x78, 0x78, 0x78)),
+    ("grey48", RGB::new(0x7a, 0x7a, 0x7a)),
+    ("grey49", RGB::new(0x7d, 0x7d, 0x7d)),
+    ("grey5", RGB::new(0x0d, 0x0d, 0x0d)),
+    ("grey50", RGB::new(0x7f, 0x7f, 0x7f)),
+    ("grey51", RGB::new(0x82, 0x82, 0x82)),
+    ("grey52", RGB::new(0x85, 0x85, 0x85)),
+    ("grey53", RGB::new(0x87, 0x87, 0x87)),
+    ("grey54", RGB::new(0x8a, 0x8a, 0x8a)),
+    ("grey55", RGB::new(0x8c, 0x8c, 0x8c)),
+    ("grey56", RGB::new(0x8f, 0x8f, 0x8f)),
+    ("grey57", RGB::new(0x91, 0x91, 0x91)),
+    ("grey58", RGB::new(0x94, 0x94, 0x94)),
+    ("grey59", RGB::new(0x96, 0x96, 0x96)),
+    ("grey6", RGB::new(0x0f, 0x0f, 0x0f)),
+    ("grey60", RGB::new(0x99, 0x99, 0x99)),
+    ("grey61", RGB::new(0x9c, 0x9c, 0x9c)),
+    ("grey62", RGB::new(0x9e, 0x9e, 0x9e)),
+    ("grey63", RGB::new(0xa1, 0xa1, 0xa1)),
+    ("grey64", RGB::new(0xa3, 0xa3, 0xa3)),
+    ("grey65", RGB::new(0xa6, 0xa6, 0xa6)),
+    ("grey66", RGB::new(0xa8, 0xa8, 0xa8)),
+    ("grey67", RGB::new(0xab, 0xab, 0xab)),
+    ("grey68", RGB::new(0xad, 0xad, 0xad)),
+    ("grey69", RGB::new(0xb0, 0xb0, 0xb0)),
+    ("grey7", RGB::new(0x12, 0x12, 0x12)),
+    ("grey70", RGB::new(0xb3, 0xb3, 0xb3)),
+    ("grey71", RGB::new(0xb5, 0xb5, 0xb5)),
+    ("grey72", RGB::new(0xb8, 0xb8, 0xb8)),
+    ("grey73", RGB::new(0xba, 0xba, 0xba)),
+    ("grey74", RGB::new(0xbd, 0xbd, 0xbd)),
+    ("grey75", RGB::new(0xbf, 0xbf, 0xbf)),
+    ("grey76", RGB::new(0xc2, 0xc2, 0xc2)),
+    ("grey77", RGB::new(0xc4, 0xc4, 0xc4)),
+    ("grey78", RGB::new(0xc7, 0xc7, 0xc7)),
+    ("grey79", RGB::new(0xc9, 0xc9, 0xc9)),
+    ("grey8", RGB::new(0x14, 0x14, 0x14)),
+    ("grey80", RGB::new(0xcc, 0xcc, 0xcc)),
+    ("grey81", RGB::new(0xcf, 0xcf, 0xcf)),
+    ("grey82", RGB::new(0xd1, 0xd1, 0xd1)),
+    ("grey83", RGB::new(0xd4, 0xd4, 0xd4)),
+    ("grey84", RGB::new(0xd6, 0xd6, 0xd6)),
+    ("grey85", RGB::new(0xd9, 0xd9, 0xd9)),
+    ("grey86", RGB::new(0xdb, 0xdb, 0xdb)),
+    ("grey87", RGB::new(0xde, 0xde, 0xde)),
+    ("grey88", RGB::new(0xe0, 0xe0, 0xe0)),
+    ("grey89", RGB::new(0xe3, 0xe3, 0xe3)),
+    ("grey9", RGB::new(0x17, 0x17, 0x17)),
+    ("grey90", RGB::new(0xe5, 0xe5, 0xe5)),
+    ("grey91", RGB::new(0xe8, 0xe8, 0xe8)),
+    ("grey92", RGB::new(0xeb, 0xeb, 0xeb)),
+    ("grey93", RGB::new(0xed, 0xed, 0xed)),
+    ("grey94", RGB::new(0xf0, 0xf0, 0xf0)),
+    ("grey95", RGB::new(0xf2, 0xf2, 0xf2)),
+    ("grey96", RGB::new(0xf5, 0xf5, 0xf5)),
+    ("grey97", RGB::new(0xf7, 0xf7, 0xf7)),
+    ("grey98", RGB::new(0xfa, 0xfa, 0xfa)),
+    ("grey99", RGB::new(0xfc, 0xfc, 0xfc)),
+    ("honeydew", RGB::new(0xf0, 0xff, 0xf0)),
+    ("honeydew1", RGB::new(0xf0, 0xff, 0xf0)),
+    ("honeydew2", RGB::new(0xe0, 0xee, 0xe0)),
+    ("honeydew3", RGB::new(0xc1, 0xcd, 0xc1)),
+    ("honeydew4", RGB::new(0x83, 0x8b, 0x83)),
+    ("hotpink", RGB::new(0xff, 0x69, 0xb4)),
+    ("hotpink1", RGB::new(0xff, 0x6e, 0xb4)),
+    ("hotpink2", RGB::new(0xee, 0x6a, 0xa7)),
+    ("hotpink3", RGB::new(0xcd, 0x60, 0x90)),
+    ("hotpink4", RGB::new(0x8b, 0x3a, 0x62)),
+    ("indianred", RGB::new(0xcd, 0x5c, 0x5c)),
+    ("indianred1", RGB::new(0xff, 0x6a, 0x6a)),
+    ("indianred2", RGB::new(0xee, 0x63, 0x63)),
+    ("indianred3", RGB::new(0xcd, 0x55, 0x55)),
+    ("indianred4", RGB::new(0x8b, 0x3a, 0x3a)),
+    ("ivory", RGB::new(0xff, 0xff, 0xf0)),
+    ("ivory1", RGB::new(0xff, 0xff, 0xf0)),
+    ("ivory2", RGB::new(0xee, 0xee, 0xe0)),
+    ("ivory3", RGB::new(0xcd, 0xcd, 0xc1)),
+    ("ivory4", RGB::new(0x8b, 0x8b, 0x83)),
+    ("khaki", RGB::new(0xf0, 0xe6, 0x8c)),
+    ("khaki1", RGB::new(0xff, 0xf6, 0x8f)),
+    ("khaki2", RGB::new(0xee, 0xe6, 0x85)),
+    ("khaki3", RGB::new(0xcd, 0xc6, 0x73)),
+    ("khaki4", RGB::new(0x8b, 0x86, 0x4e)),
+    ("lavender", RGB::new(0xe6, 0xe6, 0xfa)),
+    ("lavenderblush", RGB::new(0xff, 0xf0, 0xf5)),
+    ("lavenderblush1", RGB::new(0xff, 0xf0, 0xf5)),
+    ("lavenderblush2", RGB::new(0xee, 0xe0, 0xe5)),
+    ("lavenderblush3", RGB::new(0xcd, 0xc1, 0xc5)),
+    ("lavenderblush4", RGB::new(0x8b, 0x83, 0x86)),
+    ("lawngreen", RGB::new(0x7c, 0xfc, 0x00)),
+    ("lemonchiffon", RGB::new(0xff, 0xfa, 0xcd)),
+    ("lemonchiffon1", RGB::new(0xff, 0xfa, 0xcd)),
+    ("lemonchiffon2", RGB::new(0xee, 0xe9, 0xbf)),
+    ("lemonchiffon3", RGB::new(0xcd, 0xc9, 0xa5)),
+    ("lemonchiffon4", RGB::new(0x8b, 0x89, 0x70)),
+    ("lightblue", RGB::new(0xad, 0xd8, 0xe6)),
+    ("lightblue1", RGB::new(0xbf, 0xef, 0xff)),
+    ("lightblue2", RGB::new(0xb2, 0xdf, 0xee)),
+    ("lightblue3", RGB::new(0x9a, 0xc0, 0xcd)),
+    ("lightblue4", RGB::new(0x68, 0x83, 0x8b)),
+    ("lightcoral", RGB::new(0xf0, 0x80, 0x80)),
+    ("lightcyan", RGB::new(0xe0, 0xff, 0xff)),
+    ("lightcyan1", RGB::new(0xe0, 0xff, 0xff)),
+    ("lightcyan2", RGB::new(0xd1, 0xee, 0xee)),
+    ("lightcyan3", RGB::new(0xb4, 0xcd, 0xcd)),
+    ("lightcyan4", RGB::new(0x7a, 0x8b, 0x8b)),
+    ("lightgoldenrod", RGB::new(0xee, 0xdd, 0x82)),
+    ("lightgoldenrod1", RGB::new(0xff, 0xec, 0x8b)),
+    ("lightgoldenrod2", RGB::new(0xee, 0xdc, 0x82)),
+    ("lightgoldenrod3", RGB::new(0xcd, 0xbe, 0x70)),
+    ("lightgoldenrod4", RGB::new(0x8b, 0x81, 0x4c)),
+    ("lightgoldenrodyellow", RGB::new(0xfa, 0xfa, 0xd2)),
+    ("lightgray", RGB::new(0xd3, 0xd3, 0xd3)),
+    ("lightgreen", RGB::new(0x90, 0xee, 0x90)),
+    ("lightgrey", RGB::new(0xd3, 0xd3, 0xd3)),
+    ("lightpink", RGB::new(0xff, 0xb6, 0xc1)),
+    ("lightpink1", RGB::new(0xff, 0xae, 0xb9)),
+    ("lightpink2", RGB::new(0xee, 0xa2, 0xad)),
+    ("lightpink3", RGB::new(0xcd, 0x8c, 0x95)),
+    ("lightpink4", RGB::new(0x8b, 0x5f, 0x65)),
+    ("lightsalmon", RGB::new(0xff, 0xa0, 0x7a)),
+    ("lightsalmon1", RGB::new(0xff, 0xa0, 0x7a)),
+    ("lightsalmon2", RGB::new(0xee, 0x95, 0x72)),
+    ("lightsalmon3", RGB::new(0xcd, 0x81, 0x62)),
+    ("lightsalmon4", RGB::new(0x8b, 0x57, 0x42)),
+    ("lightseagreen", RGB::new(0x20, 0xb2, 0xaa)),
+    ("lightskyblue", RGB::new(0x87, 0xce, 0xfa)),
+    ("lightskyblue1", RGB::new(0xb0, 0xe2, 0xff)),
+    ("lightskyblue2", RGB::new(0xa4, 0xd3, 0xee)),
+    ("lightskyblue3", RGB::new(0x8d, 0xb6, 0xcd)),
+    ("lightskyblue4", RGB::new(0x60, 0x7b, 0x8b)),
+    ("lightslateblue", RGB::new(0x84, 0x70, 0xff)),
+    ("lightslategray", RGB::new(0x77, 0x88, 0x99)),
+    ("lightslategrey", RGB::new(0x77, 0x88, 0x99)),
+    ("lightsteelblue", RGB::new(0xb0, 0xc4, 0xde)),
+    ("lightsteelblue1", RGB::new(0xca, 0xe1, 0xff)),
+    ("lightsteelblue2", RGB::new(0xbc, 0xd2, 0xee)),
+    ("lightsteelblue3", RGB::new(0xa2, 0xb5, 0xcd)),
+    ("lightsteelblue4", RGB::new(0x6e, 0x7b, 0x8b)),
+    ("lightyellow", RGB::new(0xff, 0xff, 0xe0)),
+    ("lightyellow1", RGB::new(0xff, 0xff, 0xe0)),
+    ("lightyellow2", RGB::new(0xee, 0xee, 0xd1)),
+    ("lightyellow3", RGB::new(0xcd, 0xcd, 0xb4)),
+    ("lightyellow4", RGB::new(0x8b, 0x8b, 0x7a)),
+    ("limegreen", RGB::new(0x32, 0xcd, 0x32)),
+    ("linen", RGB::new(0xfa, 0xf0, 0xe6)),
+    ("magenta", RGB::new(0xff, 0x00, 0xff)),
+    ("magenta1", RGB::new(0xff, 0x00, 0xff)),
+    ("magenta2", RGB::new(0xee, 0x00, 0xee)),
+    ("magenta3", RGB::new(0xcd, 0x00, 0xcd)),
+    ("magenta4", RGB::new(0x8b, 0x00, 0x8b)),
+    ("maroon", RGB::new(0xb0, 0x30, 0x60)),
+    ("maroon1", RGB::new(0xff, 0x34, 0xb3)),
+    ("maroon2", RGB::new(0xee, 0x30, 0xa7)),
+    ("maroon3", RGB::new(0xcd, 0x29, 0x90)),
+    ("maroon4", RGB::new(0x8b, 0x1c, 0x62)),
+    ("mediumaquamarine", RGB::new(0x66, 0xcd, 0xaa)),
+    ("mediumblue", RGB::new(0x00, 0x00, 0xcd)),
+    ("mediumorchid", RGB::new(0xba, 0x55, 0xd3)),
+    ("mediumorchid1", RGB::new(0xe0, 0x66, 0xff)),
+    ("mediumorchid2", RGB::new(0xd1, 0x5f, 0xee)),
+    ("mediumorchid3", RGB::new(0xb4, 0x52, 0xcd)),
+    ("mediumorchid4", RGB::new(0x7a, 0x37, 0x8b)),
+    ("mediumpurple", RGB::new(0x93, 0x70, 0xdb)),
+    ("mediumpurple1", RGB::new(0xab, 0x82, 0xff)),
+    ("mediumpurple2", RGB::new(0x9f, 0x79, 0xee)),
+    ("mediumpurple3", RGB::new(0x89, 0x68, 0xcd)),
+    ("mediumpurple4", RGB::new(0x5d, 0x47, 0x8b)),
+    ("mediumseagreen", RGB::new(0x3c, 0xb3, 0x71)),
+    ("mediumslateblue", RGB::new(0x7b, 0x68, 0xee)),
+    ("mediumspringgreen", RGB::new(0x00, 0xfa, 0x9a)),
+    ("mediumturquoise", RGB::new(0x48, 0xd1, 0xcc)),
+    ("mediumvioletred", RGB::new(0xc7, 0x15, 0x85)),
+    ("midnightblue", RGB::new(0x19, 0x19, 0x70)),
+    ("mintcream", RGB::new(0xf5, 0xff, 0xfa)),
+    ("mistyrose", RGB::new(0xff, 0xe4, 0xe1)),
+    ("mistyrose1", RGB::new(0xff, 0xe4, 0xe1)),
+    ("mistyrose2", RGB::new(0xee, 0xd5, 0xd2)),
+    ("mistyrose3", RGB::new(0xcd, 0xb7, 0xb5)),
+    ("mistyrose4", RGB::new(0x8b, 0x7d, 0x7b)),
+    ("moccasin", RGB::new(0xff, 0xe4, 0xb5)),
+    ("navajowhite", RGB::new(0xff, 0xde, 0xad)),
+    ("navajowhite1", RGB::new(0xff, 0xde, 0xad)),
+    ("navajowhite2", RGB::new(0xee, 0xcf, 0xa1)),
+    ("navajowhite3", RGB::new(0xcd, 0xb3, 0x8b)),
+    ("navajowhite4", RGB::new(0x8b, 0x79, 0x5e)),
+    ("navy", RGB::new(0x00, 0x00, 0x80)),
+    ("navyblue", RGB::new(0x00, 0x00, 0x80)),
+    ("oldlace", RGB::new(0xfd, 0xf5, 0xe6)),
+    ("olivedrab", RGB::new(0x6b, 0x8e, 0x23)),
+    ("olivedrab1", RGB::new(0xc0, 0xff, 0x3e)),
+    ("olivedrab2", RGB::new(0xb3, 0xee, 0x3a)),
+    ("olivedrab3", RGB::new(0x9a, 0xcd, 0x32)),
+    ("olivedrab4", RGB::new(0x69, 0x8b, 0x22)),
+    ("orange", RGB::new(0xff, 0xa5, 0x00)),
+    ("orange1", RGB::new(0xff, 0xa5, 0x00)),
+    ("orange2", RGB::new(0xee, 0x9a, 0x00)),
+    ("orange3", RGB::new(0xcd, 0x85, 0x00)),
+    ("orange4", RGB::new(0x8b, 0x5a, 0x00)),
+    ("orangered", RGB::new(0xff, 0x45, 0x00)),
+    ("orangered1", RGB::new(0xff, 0x45, 0x00)),
+    ("orangered2", RGB::new(0xee, 0x40, 0x00)),
+    ("orangered3", RGB::new(0xcd, 0x37, 0x00)),
+    ("orangered4", RGB::new(0x8b, 0x25, 0x00)),
+    ("orchid", RGB::new(0xda, 0x70, 0xd6)),
+    ("orchid1", RGB::new(0xff, 0x83, 0xfa)),
+    ("orchid2", RGB::new(0xee, 0x7a, 0xe9)),
+    ("orchid3", RGB::new(0xcd, 0x69, 0xc9)),
+    ("orchid4", RGB::new(0x8b, 0x47, 0x89)),
+    ("palegoldenrod", RGB::new(0xee, 0xe8, 0xaa)),
+    ("palegreen", RGB::new(0x98, 0xfb, 0x98)),
+    ("palegreen1", RGB::new(0x9a, 0xff, 0x9a)),
+    ("palegreen2", RGB::new(0x90, 0xee, 0x90)),
+    ("palegreen3", RGB::new(0x7c, 0xcd, 0x7c)),
+    ("palegreen4", RGB::new(0x54, 0x8b, 0x54)),
+    ("paleturquoise", RGB::new(0xaf, 0xee, 0xee)),
+    ("paleturquoise1", RGB::new(0xbb, 0xff, 0xff)),
+    ("paleturquoise2", RGB::new(0xae, 0xee, 0xee)),
+    ("paleturquoise3", RGB::new(0x96, 0xcd, 0xcd)),
+    ("paleturquoise4", RGB::new(0x66, 0x8b, 0x8b)),
+    ("palevioletred", RGB::new(0xdb, 0x70, 0x93)),
+    ("palevioletred1", RGB::new(0xff, 0x82, 0xab)),
+    ("palevioletred2", RGB::new(0xee, 0x79, 0x9f)),
+    ("palevioletred3", RGB::new(0xcd, 0x68, 0x89)),
+    ("palevioletred4", RGB::new(0x8b, 0x47, 0x5d)),
+    ("papayawhip", RGB::new(0xff, 0xef, 0xd5)),
+    ("peachpuff", RGB::new(0xff, 0xda, 0xb9)),
+    ("peachpuff1", RGB::new(0xff, 0xda, 0xb9)),
+    ("peachpuff2", RGB::new(0xee, 0xcb, 0xad)),
+    ("peachpuff3", RGB::new(0xcd, 0xaf, 0x95)),
+    ("peachpuff4", RGB::new(0x8b, 0x77, 0x65)),
+    ("peru", RGB::new(0xcd, 0x85, 0x3f)),
+    ("pink", RGB::new(0xff, 0xc0, 0xcb)),
+    ("pink1", RGB::new(0xff, 0xb5, 0xc5)),
+    ("pink2", RGB::new(0xee, 0xa9, 0xb8)),
+    ("pink3", RGB::new(0xcd, 0x91, 0x9e)),
+    ("pink4", RGB::new(0x8b, 0x63, 0x6c)),
+    ("plum", RGB::new(0xdd, 0xa0, 0xdd)),
+    ("plum1", RGB::new(0xff, 0xbb, 0xff)),
+    ("plum2", RGB::new(0xee, 0xae, 0xee)),
+    ("plum3", RGB::new(0xcd, 0x96, 0xcd)),
+    ("plum4", RGB::new(0x8b, 0x66, 0x8b)),
+    ("powderblue", RGB::new(0xb0, 0xe0, 0xe6)),
+    ("purple", RGB::new(0xa0, 0x20, 0xf0)),
+    ("purple1", RGB::new(0x9b, 0x30, 0xff)),
+    ("purple2", RGB::new(0x91, 0x2c, 0xee)),
+    ("purple3", RGB::new(0x7d, 0x26, 0xcd)),
+    ("purple4", RGB::new(0x55, 0x1a, 0x8b)),
+    ("red", RGB::new(0xff, 0x00, 0x00)),
+    ("red1", RGB::new(0xff, 0x00, 0x00)),
+    ("red2", RGB::new(0xee, 0x00, 0x00)),
+    ("red3", RGB::new(0xcd, 0x00, 0x00)),
+    ("red4", RGB::new(0x8b, 0x00, 0x00)),
+    ("rosybrown", RGB::new(0xbc, 0x8f, 0x8f)),
+    ("rosybrown1", RGB::new(0xff, 0xc1, 0xc1)),
+    ("rosybrown2", RGB::new(0xee, 0xb4, 0xb4)),
+    ("rosybrown3", RGB::new(0xcd, 0x9b, 0x9b)),
+    ("rosybrown4", RGB::new(0x8b, 0x69, 0x69)),
+    ("royalblue", RGB::new(0x41, 0x69, 0xe1)),
+    ("royalblue1", RGB::new(0x48, 0x76, 0xff)),
+    ("royalblue2", RGB::new(0x43, 0x6e, 0xee)),
+    ("royalblue3", RGB::new(0x3a, 0x5f, 0xcd)),
+    ("royalblue4", RGB::new(0x27, 0x40, 0x8b)),
+    ("saddlebrown", RGB::new(0x8b, 0x45, 0x13)),
+    ("salmon", RGB::new(0xfa, 0x80, 0x72)),
+    ("salmon1", RGB::new(0xff, 0x8c, 0x69)),
+    ("salmon2", RGB::new(0xee, 0x82, 0x62)),
+    ("salmon3", RGB::new(0xcd, 0x70, 0x54)),
+    ("salmon4", RGB::new(0x8b, 0x4c, 0x39)),
+    ("sandybrown", RGB::new(0xf4, 0xa4, 0x60)),
+    ("seagreen", RGB::new(0x2e, 0x8b, 0x57)),
+    ("seagreen1", RGB::new(0x54, 0xff, 0x9f)),
+    ("seagreen2", RGB::new(0x4e, 0xee, 0x94)),
+    ("seagreen3", RGB::new(0x43, 0xcd, 0x80)),
+    ("seagreen4", RGB::new(0x2e, 0x8b, 0x57)),
+    ("seashell", RGB::new(0xff, 0xf5, 0xee)),
+    ("seashell1", RGB::new(0xff, 0xf5, 0xee)),
+    ("seashell2", RGB::new(0xee, 0xe5, 0xde)),
+    ("seashell3", RGB::new(0xcd, 0xc5, 0xbf)),
+    ("seashell4", RGB::new(0x8b, 0x86, 0x82)),
+    ("sienna", RGB::new(0xa0, 0x52, 0x2d)),
+    ("sienna1", RGB::new(0xff, 0x82, 0x47)),
+    ("sienna2", RGB::new(0xee, 0x79, 0x42)),
+    ("sienna3", RGB::new(0xcd, 0x68, 0x39)),
+    ("sienna4", RGB::new(0x8b, 0x47, 0x26)),
+    ("skyblue", RGB::new(0x87, 0xce, 0xeb)),
+    ("skyblue1", RGB::new(0x87, 0xce, 0xff)),
+    ("skyblue2", RGB::new(0x7e, 0xc0, 0xee)),
+    ("skyblue3", RGB::new(0x6c, 0xa6, 0xcd)),
+    ("skyblue4", RGB::new(0x4a, 0x70, 0x8b)),
+    ("slateblue", RGB::new(0x6a, 0x5a, 0xcd)),
+    ("slateblue1", RGB::new(0x83, 0x6f, 0xff)),
+    ("slateblue2", RGB::new(0x7a, 0x67, 0xee)),
+    ("slateblue3", RGB::new(0x69, 0x59, 0xcd)),
+    ("slateblue4", RGB::new(0x47, 0x3c, 0x8b)),
+    ("slategray", RGB::new(0x70, 0x80, 0x90)),
+    ("slategray1", RGB::new(0xc6, 0xe2, 0xff)),
+    ("slategray2", RGB::new(0xb9, 0xd3, 0xee)),
+    ("slategray3", RGB::new(0x9f, 0xb6, 0xcd)),
+    ("slategray4", RGB::new(0x6c, 0x7b, 0x8b)),
+    ("slategrey", RGB::new(0x70, 0x80, 0x90)),
+    ("snow", RGB::new(0xff, 0xfa, 0xfa)),
+    ("snow1", RGB::new(0xff, 0xfa, 0xfa)),
+    ("snow2", RGB::new(0xee, 0xe9, 0xe9)),
+    ("snow3", RGB::new(0xcd, 0xc9, 0xc9)),
+    ("snow4", RGB::new(0x8b, 0x89, 0x89)),
+    ("springgreen", RGB::new(0x00, 0xff, 0x7f)),
+    ("springgreen1", RGB::new(0x00, 0xff, 0x7f)),
+    ("springgreen2", RGB::new(0x00, 0xee, 0x76)),
+    ("springgreen3", RGB::new(0x00, 0xcd, 0x66)),
+    ("springgreen4", RGB::new(0x00, 0x8b, 0x45)),
+    ("steelblue", RGB::new(0x46, 0x82, 0xb4)),
+    ("steelblue1", RGB::new(0x63, 0xb8, 0xff)),
+    ("steelblue2", RGB::new(0x5c, 0xac, 0xee)),
+    ("steelblue3", RGB::new(0x4f, 0x94, 0xcd)),
+    ("steelblue4", RGB::new(0x36, 0x64, 0x8b)),
+    ("tan", RGB::new(0xd2, 0xb4, 0x8c)),
+    ("tan1", RGB::new(0xff, 0xa5, 0x4f)),
+    ("tan2", RGB::new(0xee, 0x9a, 0x49)),
+    ("tan3", RGB::new(0xcd, 0x85, 0x3f)),
+    ("tan4", RGB::new(0x8b, 0x5a, 0x2b)),
+    ("thistle", RGB::new(0xd8, 0xbf, 0xd8)),
+    ("thistle1", RGB::new(0xff, 0xe1, 0xff)),
+    ("thistle2", RGB::new(0xee, 0xd2, 0xee)),
+    ("thistle3", RGB::new(0xcd, 0xb5, 0xcd)),
+    ("thistle4", RGB::new(0x8b, 0x7b, 0x8b)),
+    ("tomato", RGB::new(0xff, 0x63, 0x47)),
+    ("tomato1", RGB::new(0xff, 0x63, 0x47)),
+    ("tomato2", RGB::new(0xee, 0x5c, 0x42)),
+    ("tomato3", RGB::new(0xcd, 0x4f, 0x39)),
+    ("tomato4", RGB::new(0x8b, 0x36, 0x26)),
+    ("turquoise", RGB::new(0x40, 0xe0, 0xd0)),
+    ("turquoise1", RGB::new(0x00, 0xf5, 0xff)),
+    ("turquoise2", RGB::new(0x00, 0xe5, 0xee)),
+    ("turquoise3", RGB::new(0x00, 0xc5, 0xcd)),
+    ("turquoise4", RGB::new(0x00, 0x86, 0x8b)),
+    ("violet", RGB::new(0xee, 0x82, 0xee)),
+    ("violetred", RGB::new(0xd0, 0x20, 0x90)),
+    ("violetred1", RGB::new(0xff, 0x3e, 0x96)),
+    ("violetred2", RGB::new(0xee, 0x3a, 0x8c)),
+    ("violetred3", RGB::new(0xcd, 0x32, 0x78)),
+    ("violetred4", RGB::new(0x8b, 0x22, 0x52)),
+    ("wheat", RGB::new(0xf5, 0xde, 0xb3)),
+    ("wheat1", RGB::new(0xff, 0xe7, 0xba)),
+    ("wheat2", RGB::new(0xee, 0xd8, 0xae)),
+    ("wheat3", RGB::new(0xcd, 0xba, 0x96)),
+    ("wheat4", RGB::new(0x8b, 0x7e, 0x66)),
+    ("white", RGB::new(0xff, 0xff, 0xff)),
+    ("whitesmoke", RGB::new(0xf5, 0xf5, 0xf5)),
+    ("yellow", RGB::new(0xff, 0xff, 0x00)),
+    ("yellow1", RGB::new(0xff, 0xff, 0x00)),
+    ("yellow2", RGB::new(0xee, 0xee, 0x00)),
+    ("yellow3", RGB::new(0xcd, 0xcd, 0x00)),
+    ("yellow4", RGB::new(0x8b, 0x8b, 0x00)),
+    ("yellowgreen", RGB::new(0x9a, 0xcd, 0x32)),
+];
+
+/// Looks up `name` in a sorted table. `name` must already be normalised.
+fn lookup(table: &[(&str, RGB)], name: &str) -> Option<RGB> {
+    table
+        .binary_search_by(|(entry, _)| (*entry).cmp(name))
+        .ok()
+        .map(|idx| table[idx].1)
+}
+
+/// Finds the entry of `table` closest to `colour` by deltaEOK, the
+/// Euclidean distance in Oklab.
+fn nearest(table: &'static [(&'static str, RGB)], colour: RGB) -> &'static str {
+    let target = Oklab::from(colour);
+
+    table
+        .iter()
+        .map(|(name, c)| (name, Oklab::from(*c).delta_e(&target)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(name, _)| *name)
+        .unwrap()
+}
+
+impl RGB {
+    /// Looks up a CSS named colour such as `"rebeccapurple"`, ignoring ASCII
+    /// case.
+    pub fn from_name<S: AsRef<str>>(name: S) -> Option<Self> {
+        lookup(CSS_COLOURS, &name.as_ref().to_ascii_lowercase())
+    }
+
+    /// The CSS name of this exact colour, if it has one.
+    ///
+    /// Where several names share a colour, such as `aqua` and `cyan`, the
+    /// alphabetically first is returned.
+    pub fn name(&self) -> Option<&'static str> {
+        CSS_COLOURS
+            .iter()
+            .find(|(_, c)| c == self)
+            .map(|(name, _)| *name)
+    }
+
+    /// The CSS named colour perceptually closest to this one.
+    pub fn nearest_name(&self) -> &'static str {
+        nearest(CSS_COLOURS, *self)
+    }
+
+    /// Every CSS named colour with its value, sorted by name.
+    pub fn named_colours() -> &'static [(&'static str, RGB)] {
+        CSS_COLOURS
+    }
+
+    /// Looks up an X11 colour name such as `"DarkOliveGreen3"` or
+    /// `"dark olive green"`, ignoring ASCII case and spaces.
+    #[cfg(feature = "x11")]
+    pub fn from_x11_name<S: AsRef<str>>(name: S) -> Option<Self> {
+        let name: String = name
+            .as_ref()
+            .chars()
+            .filter(|c| *c != ' ')
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        lookup(X11_COLOURS, &name)
+    }
+
+    /// The X11 colour perceptually closest to this one.
+    #[cfg(feature = "x11")]
+    pub fn nearest_x11_name(&self) -> &'static str {
+        nearest(X11_COLOURS, *self)
+    }
+
+    /// Every X11 colour with its value, sorted by normalised name.
+    #[cfg(feature = "x11")]
+    pub fn x11_colours() -> &'static [(&'static str, RGB)] {
+        X11_COLOURS
+    }
+}
@@ -0,0 +1,123 @@
+//! The Oklab and Oklch colour spaces.
+
+use crate::colour::{convert, gamut, LinearRgb, RGB};
+
+/// A colour in Björn Ottosson's Oklab perceptual colour space.
+///
+/// `l` is lightness from `0.0` to `1.0`; `a` and `b` are the green–red and
+/// blue–yellow axes, roughly within `-0.4..=0.4`. Euclidean distance in
+/// Oklab is a good measure of perceived difference, which CSS calls
+/// deltaEOK.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Oklab {
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+
+    pub(crate) fn from_array([l, a, b]: convert::Vec3) -> Self {
+        Self::new(l, a, b)
+    }
+
+    pub(crate) fn to_array(self) -> convert::Vec3 {
+        [self.l, self.a, self.b]
+    }
+
+    /// Converts from linear-light sRGB channels, nominally in `0.0..=1.0`.
+    pub fn from_linear_srgb(rgb: [f64; 3]) -> Self {
+        Self::from_array(convert::linear_srgb_to_oklab(rgb))
+    }
+
+    /// Converts to linear-light sRGB channels. Colours outside the sRGB
+    /// gamut give channels outside `0.0..=1.0`.
+    pub fn to_linear_srgb(self) -> [f64; 3] {
+        convert::oklab_to_linear_srgb(self.to_array())
+    }
+
+    /// The deltaEOK colour difference: the Euclidean distance between two
+    /// colours.
+    pub fn delta_e(&self, other: &Oklab) -> f64 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+}
+
+impl From<RGB> for Oklab {
+    fn from(rgb: RGB) -> Self {
+        Self::from_linear_srgb(LinearRgb::from(rgb).to_array())
+    }
+}
+
+impl From<Oklab> for RGB {
+    /// Converts to sRGB, mapping anything outside the sRGB gamut into it with
+    /// [`GamutMapping::Css`](crate::colour::GamutMapping::Css).
+    fn from(lab: Oklab) -> Self {
+        gamut::linear_srgb_to_rgb(lab.to_linear_srgb())
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(lch: Oklch) -> Self {
+        Self::from_array(convert::polar_to_rect(lch.to_array()))
+    }
+}
+
+/// A colour in Oklch: [`Oklab`] in cylindrical form.
+///
+/// `l` is the same lightness as in Oklab, `c` is chroma, roughly within
+/// `0.0..=0.4`, and `h` is the hue angle in degrees within `0.0..360.0`.
+/// Greys have a chroma of zero, or very nearly, and their hue has no effect.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Oklch {
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Self { l, c, h }
+    }
+
+    pub(crate) fn from_array([l, c, h]: convert::Vec3) -> Self {
+        Self::new(l, c, h)
+    }
+
+    pub(crate) fn to_array(self) -> convert::Vec3 {
+        [self.l, self.c, self.h]
+    }
+
+    /// Converts from linear-light sRGB channels, nominally in `0.0..=1.0`.
+    pub fn from_linear_srgb(rgb: [f64; 3]) -> Self {
+        Oklab::from_linear_srgb(rgb).into()
+    }
+
+    /// Converts to linear-light sRGB channels. Colours outside the sRGB
+    /// gamut give channels outside `0.0..=1.0`.
+    pub fn to_linear_srgb(self) -> [f64; 3] {
+        Oklab::from(self).to_linear_srgb()
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(lab: Oklab) -> Self {
+        Self::from_array(convert::rect_to_polar(lab.to_array()))
+    }
+}
+
+impl From<RGB> for Oklch {
+    fn from(rgb: RGB) -> Self {
+        Oklab::from(rgb).into()
+    }
+}
+
+impl From<Oklch> for RGB {
+    fn from(lch: Oklch) -> Self {
+        Oklab::from(lch).into()
+    }
+}
@@ -0,0 +1,166 @@
+//! Parsing colours from strings.
+
+use std::{
+    error::Error,
+    fmt,
+    ops::Range,
+};
+
+/// The ways parsing a colour from a string can fail.
+///
+/// Positions are byte offsets into the original input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColourParseError {
+    /// The input was empty, or held nothing but a `#`.
+    Empty,
+    /// A hex colour had a number of digits other than 3, 4, 6 or 8.
+    InvalidLength { digits: usize },
+    /// A character that is not a hex digit was found.
+    InvalidDigit { found: char, position: usize },
+    /// The input ended while more was expected.
+    UnexpectedEnd { position: usize },
+    /// A token was found where something else was expected.
+    UnexpectedToken { expected: &'static str, span: Range<usize> },
+    /// A character that cannot start any CSS token was found.
+    InvalidCharacter { found: char, position: usize },
+    /// A function name that is not a CSS colour function.
+    UnknownFunction { name: String, span: Range<usize> },
+    /// A keyword that is not a colour name.
+    UnknownKeyword { name: String, span: Range<usize> },
+    /// A `color()` colour space that is not supported.
+    UnknownColourSpace { name: String, span: Range<usize> },
+    /// A dimension with a unit that makes no sense in its position.
+    InvalidUnit { unit: String, span: Range<usize> },
+}
+
+impl ColourParseError {
+    /// The byte range of the input the error refers to, if it refers to a
+    /// particular part of it.
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            Self::Empty | Self::InvalidLength { .. } => None,
+            Self::InvalidDigit { found, position }
+            | Self::InvalidCharacter { found, position } => {
+                Some(*position..*position + found.len_utf8())
+            }
+            Self::UnexpectedEnd { position } => Some(*position..*position),
+            Self::UnexpectedToken { span, .. }
+            | Self::UnknownFunction { span, .. }
+            | Self::UnknownKeyword { span, .. }
+            | Self::UnknownColourSpace { span, .. }
+            | Self::InvalidUnit { span, .. } => Some(span.clone()),
+        }
+    }
+
+    /// Moves any position in the error along by `offset` bytes, for errors
+    /// from parsing a slice of a larger input.
+    pub(crate) fn offset(self, offset: usize) -> Self {
+        match self {
+            Self::InvalidDigit { found, position } => Self::InvalidDigit {
+                found,
+                position: position + offset,
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for ColourParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty colour string"),
+            Self::InvalidLength { digits } => write!(
+                f,
+                "hex colour has {} digits, expected 3, 4, 6 or 8",
+                digits
+            ),
+            Self::InvalidDigit { found, position } => write!(
+                f,
+                "invalid hex digit {:?} at position {}",
+                found, position
+            ),
+            Self::UnexpectedEnd { position } => {
+                write!(f, "unexpected end of input at position {}", position)
+            }
+            Self::UnexpectedToken { expected, span } => write!(
+                f,
+                "expected {} at {}..{}",
+                expected, span.start, span.end
+            ),
+            Self::InvalidCharacter { found, position } => write!(
+                f,
+                "unexpected character {:?} at position {}",
+                found, position
+            ),
+            Self::UnknownFunction { name, span } => write!(
+                f,
+                "unknown colour function {:?} at {}..{}",
+                name, span.start, span.end
+            ),
+            Self::UnknownKeyword { name, span } => write!(
+                f,
+                "unknown colour keyword {:?} at {}..{}",
+                name, span.start, span.end
+            ),
+            Self::UnknownColourSpace { name, span } => write!(
+                f,
+                "unknown colour space {:?} at {}..{}",
+                name, span.start, span.end
+            ),
+            Self::InvalidUnit { unit, span } => write!(
+                f,
+                "unexpected unit {:?} at {}..{}",
+                unit, span.start, span.end
+            ),
+        }
+    }
+}
+
+impl Error for ColourParseError {}
+
+/// Parses a hex colour in any of the `rgb`, `rgba`, `rrggbb` or `rrggbbaa`
+/// forms, with or without a leading `#`, into `[r, g, b, a]`.
+///
+/// The short forms repeat each digit, so `#f80` is `#ff8800`. Alpha defaults
+/// to `0xff` when it is not given.
+pub(crate) fn parse_hex(input: &str) -> Result<[u8; 4], ColourParseError> {
+    let (offset, digits) = match input.strip_prefix('#') {
+        Some(rest) => (1, rest),
+        None => (0, input),
+    };
+
+    if digits.is_empty() {
+        return Err(ColourParseError::Empty);
+    }
+
+    let mut values = Vec::with_capacity(8);
+    for (idx, c) in digits.char_indices() {
+        match c.to_digit(16) {
+            Some(v) => values.push(v as u8),
+            None => {
+                return Err(ColourParseError::InvalidDigit {
+                    found: c,
+                    position: offset + idx,
+                })
+            }
+        }
+    }
+
+    match values.len() {
+        3 | 4 => {
+            let mut out = [0xff; 4];
+            for (o, v) in out.iter_mut().zip(&values) {
+                *o = v * 0x11;
+            }
+            Ok(out)
+        }
+        6 | 8 => {
+            let mut out = [0xff; 4];
+            for (o, pair) in out.iter_mut().zip(values.chunks(2)) {
+                *o = pair[0] << 4 | pair[1];
+            }
+            Ok(out)
+        }
+        digits => Err(ColourParseError::InvalidLength { digits }),
+    }
+}
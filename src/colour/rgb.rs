@@ -0,0 +1,274 @@
+use crate::colour::{
+    css::parse_css_colour,
+    parse::{parse_hex, ColourParseError},
+    Component,
+};
+
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng
+};
+
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt,
+    str::FromStr,
+}; 
+
+/// How far from one the weights given to [`RGB::blend`] may add up to
+/// before they are rejected rather than normalised.
+pub const BLEND_TOLERANCE: f64 = 1e-6;
+
+/// An sRGB colour with channels of type `T`; see [`Component`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)] 
+pub struct Rgb<T> {
+    r: T,
+    g: T,
+    b: T,
+}
+
+/// An sRGB colour with 8 bits per channel.
+pub type RGB = Rgb<u8>;
+
+impl<T: Component> Rgb<T> {
+    pub const fn new(r: T, g: T, b: T) -> Self {
+        Self {
+            r, 
+            g,
+            b,
+        }
+    }
+
+    pub fn to_tuple(&self) -> (T, T, T) {
+        (self.r, self.g, self.b) 
+    }
+
+    /// The channels as fractions of full intensity, so `0.0..=1.0` for
+    /// integer channels.
+    pub fn to_unit(&self) -> [f64; 3] {
+        [self.r, self.g, self.b].map(T::to_unit)
+    }
+
+    /// Builds a colour from channels as fractions of full intensity. Integer
+    /// channels clip anything outside `0.0..=1.0` and round to the nearest
+    /// value; float channels keep the values as they are.
+    pub fn from_unit([r, g, b]: [f64; 3]) -> Self {
+        Self::new(T::from_unit(r), T::from_unit(g), T::from_unit(b))
+    }
+
+    /// Converts to another channel type, rounding to the nearest value if it
+    /// has fewer levels. Widening conversions, such as `u8` to `u16`, are
+    /// exact and also available through `From`.
+    pub fn convert<U: Component>(&self) -> Rgb<U> {
+        Rgb::from_unit(self.to_unit())
+    }
+
+    /// Mixes `alpha` of this colour with `beta` of `other`, in gamma-encoded
+    /// sRGB, rounding each channel once at the end.
+    ///
+    /// The weights must be finite, non-negative and add up to one. A sum
+    /// within [`BLEND_TOLERANCE`] of one, as floating-point arithmetic tends
+    /// to give, is normalised.
+    pub fn blend(&self, other: Self, alpha: f64, beta: f64) -> Result<Self, BlendError> {
+        let (alpha, beta) = normalise_weights(alpha, beta)?;
+        let (a, b) = (self.to_unit(), other.to_unit());
+        Ok(Self::from_unit([0, 1, 2].map(|i| a[i] * alpha + b[i] * beta)))
+    }
+
+    /// Mixes towards `other` by `t`, clamped to `0.0..=1.0`: `0.0` gives
+    /// this colour and `1.0` gives `other`, exactly. NaN gives this colour.
+    pub fn mix(&self, other: Self, t: f64) -> Self {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        self.blend(other, 1.0 - t, t)
+            .expect("weights from a clamped t are always valid")
+    }
+}
+
+impl RGB {
+    pub fn random() -> Self {
+        rand::random() 
+    }
+
+    /// Parses a hex colour such as `#ff8800`, `ff8800` or `#f80`.
+    ///
+    /// Four and eight digit forms are accepted too; their alpha digits are
+    /// checked but otherwise ignored, since `RGB` is opaque; use
+    /// [`Rgba`](crate::colour::Rgba) to keep them.
+    pub fn from_hex_string<S: AsRef<str>>(hex_string: S) -> Result<Self, ColourParseError> {
+        let [r, g, b, _] = parse_hex(hex_string.as_ref())?; 
+        Ok(RGB::new(r, g, b))
+    }
+
+    /// Parses any CSS colour value, such as `#f80`, `rgb(255 136 0)` or
+    /// `oklch(70% 0.19 50)`.
+    ///
+    /// Colours outside the sRGB gamut are mapped into it as CSS does, and
+    /// alpha is dropped; use [`parse_css_colour`] to keep them.
+    pub fn from_css_string<S: AsRef<str>>(css: S) -> Result<Self, ColourParseError> {
+        Ok(parse_css_colour(css.as_ref())?.to_rgb())
+    }
+}
+
+impl RGB {
+    fn zip_with(self, other: Self, f: impl Fn(u8, u8) -> u8) -> Self {
+        Self::new(f(self.r, other.r), f(self.g, other.g), f(self.b, other.b))
+    }
+
+    fn checked_zip_with(self, other: Self, f: impl Fn(u8, u8) -> Option<u8>) -> Option<Self> {
+        Some(Self::new(f(self.r, other.r)?, f(self.g, other.g)?, f(self.b, other.b)?))
+    }
+
+    /// Adds each channel, stopping at 255.
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.zip_with(other, u8::saturating_add)
+    }
+
+    /// Adds each channel, or gives `None` if any would pass 255.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.checked_zip_with(other, u8::checked_add)
+    }
+
+    /// Adds each channel, wrapping around past 255.
+    pub fn wrapping_add(self, other: Self) -> Self {
+        self.zip_with(other, u8::wrapping_add)
+    }
+
+    /// Subtracts each channel, stopping at 0.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self.zip_with(other, u8::saturating_sub)
+    }
+
+    /// Subtracts each channel, or gives `None` if any would go below 0.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_zip_with(other, u8::checked_sub)
+    }
+
+    /// Subtracts each channel, wrapping around below 0.
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        self.zip_with(other, u8::wrapping_sub)
+    }
+}
+
+/// Saturating addition; see [`RGB::checked_add`] and [`RGB::wrapping_add`]
+/// for the alternatives.
+impl std::ops::Add<RGB> for RGB {
+    type Output = RGB; 
+    fn add(self, other: Self) -> Self::Output {
+        self.saturating_add(other)
+    }
+}
+
+/// Saturating subtraction; see [`RGB::checked_sub`] and
+/// [`RGB::wrapping_sub`] for the alternatives.
+impl std::ops::Sub<RGB> for RGB {
+    type Output = RGB;
+    fn sub(self, other: Self) -> Self::Output {
+        self.saturating_sub(other)
+    }
+}
+
+/// Scales each channel, rounding to the nearest value and saturating at 0
+/// and 255. Scaling by NaN gives black.
+impl std::ops::Mul<f64> for RGB {
+    type Output = RGB; 
+    fn mul(self, rhs: f64) -> Self::Output {
+        let channel = |c: u8| (c as f64 * rhs).round().clamp(0.0, 255.0) as u8;
+        Self::new(channel(self.r), channel(self.g), channel(self.b))
+    }
+}
+
+/// Multiplies channel by channel, treating each as a fraction of 255, as
+/// in the multiply blend mode. The result is rounded to the nearest value.
+impl std::ops::Mul<RGB> for RGB {
+    type Output = RGB;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| ((a as u32 * b as u32 + 127) / 255) as u8)
+    }
+}
+
+/// Divides each channel, rounding to the nearest value and saturating.
+/// Dividing by `0.0` gives 255 for non-zero channels, and dividing by `-0.0`
+/// gives 0; zero channels stay 0 either way.
+impl std::ops::Div<f64> for RGB {
+    type Output = RGB;
+    fn div(self, rhs: f64) -> Self::Output {
+        let channel = |c: u8| (c as f64 / rhs).round().clamp(0.0, 255.0) as u8;
+        Self::new(channel(self.r), channel(self.g), channel(self.b))
+    }
+}
+
+impl FromStr for RGB {
+    type Err = ColourParseError; 
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex_string(s)
+    }
+}
+
+impl TryFrom<&str> for RGB {
+    type Error = ColourParseError; 
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_hex_string(s)
+    }
+}
+
+impl fmt::Display for RGB {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+macro_rules! widen {
+    ($($from:ty => $to:ty),*) => {$(
+        impl From<Rgb<$from>> for Rgb<$to> {
+            fn from(rgb: Rgb<$from>) -> Self {
+                rgb.convert()
+            }
+        }
+    )*};
+}
+
+widen!(u8 => u16, u8 => f32, u8 => f64, u16 => f32, u16 => f64, f32 => f64);
+
+impl Distribution<RGB> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RGB {
+        RGB { 
+            r: rng.gen_range(0..=255),
+            g: rng.gen_range(0..=255),
+            b: rng.gen_range(0..=255),
+        }
+    }
+}
+
+/// Checks a pair of blend weights, scaling them to add up to exactly one.
+pub(crate) fn normalise_weights(alpha: f64, beta: f64) -> Result<(f64, f64), BlendError> {
+    let sum = alpha + beta;
+    let valid = |w: f64| w.is_finite() && w >= 0.0;
+    if !valid(alpha) || !valid(beta) || (sum - 1.0).abs() > BLEND_TOLERANCE {
+        return Err(BlendError::InvalidWeights { alpha, beta });
+    }
+    Ok((alpha / sum, beta / sum))
+}
+
+/// The ways blending two colours can fail.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BlendError {
+    /// The weights were negative, not finite, or did not add up to one.
+    InvalidWeights { alpha: f64, beta: f64 },
+}
+
+impl fmt::Display for BlendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidWeights { alpha, beta } => write!(
+                f,
+                "blend weights {} and {} must be non-negative and add up to 1",
+                alpha, beta
+            ),
+        }
+    }
+}
+
+impl Error for BlendError {}
@@ -0,0 +1,220 @@
+//! RGB colour spaces beyond sRGB, such as Display P3 and Rec.2020.
+
+use crate::colour::{
+    convert::{self, Mat3, Vec3},
+    GamutMapping, WhitePoint, Xyz, RGB,
+};
+
+use std::{fmt, hash::Hash, marker::PhantomData, sync::OnceLock};
+
+/// An RGB colour space: three primaries, a reference white and a transfer
+/// function between encoded and linear-light values.
+///
+/// The spaces here are marker types for [`WideRgb`], so that colours in
+/// different spaces cannot be mixed up.
+pub trait RgbSpace: Copy + Default + fmt::Debug + PartialEq + Eq + Hash + Send + Sync + 'static {
+    /// The space's name, as used by CSS `color()` where it has one.
+    const NAME: &'static str;
+
+    /// The chromaticities of the red, green and blue primaries.
+    const PRIMARIES: [(f64, f64); 3];
+
+    /// The reference white.
+    const WHITE: WhitePoint;
+
+    /// Decodes an encoded channel to linear light. Negative values decode as
+    /// the mirror image of positive ones.
+    fn to_linear(encoded: f64) -> f64;
+
+    /// Encodes a linear-light channel, the inverse of
+    /// [`RgbSpace::to_linear`].
+    fn from_linear(linear: f64) -> f64;
+
+    /// The matrices from linear-light channels to XYZ relative to the
+    /// space's white, and back. The spaces here work them out once and cache
+    /// them; by default they are worked out afresh on every call.
+    fn xyz_matrices() -> (Mat3, Mat3) {
+        xyz_matrices(Self::PRIMARIES, Self::WHITE)
+    }
+}
+
+macro_rules! rgb_space {
+    (
+        $(#[$doc:meta])*
+        $space:ident, $name:literal, $primaries:expr, $white:expr, $to_linear:expr, $from_linear:expr
+    ) => {
+        $(#[$doc])*
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+        pub struct $space;
+
+        impl RgbSpace for $space {
+            const NAME: &'static str = $name;
+            const PRIMARIES: [(f64, f64); 3] = $primaries;
+            const WHITE: WhitePoint = $white;
+
+            fn to_linear(encoded: f64) -> f64 {
+                $to_linear(encoded)
+            }
+
+            fn from_linear(linear: f64) -> f64 {
+                $from_linear(linear)
+            }
+
+            fn xyz_matrices() -> (Mat3, Mat3) {
+                static MATRICES: OnceLock<(Mat3, Mat3)> = OnceLock::new();
+                *MATRICES.get_or_init(|| xyz_matrices(Self::PRIMARIES, Self::WHITE))
+            }
+        }
+    };
+}
+
+rgb_space!(
+    /// sRGB, the space of [`RGB`] and of the web.
+    Srgb,
+    "srgb",
+    convert::SRGB_PRIMARIES,
+    WhitePoint::D65,
+    convert::srgb_to_linear,
+    convert::linear_to_srgb
+);
+
+rgb_space!(
+    /// Display P3: the DCI-P3 primaries with the sRGB transfer function and
+    /// a D65 white, as used by wide-gamut Apple displays.
+    DisplayP3,
+    "display-p3",
+    convert::DISPLAY_P3_PRIMARIES,
+    WhitePoint::D65,
+    convert::srgb_to_linear,
+    convert::linear_to_srgb
+);
+
+rgb_space!(
+    /// ITU-R BT.2020, the space of UHD television, with its SDR transfer
+    /// function.
+    Rec2020,
+    "rec2020",
+    convert::REC2020_PRIMARIES,
+    WhitePoint::D65,
+    convert::rec2020_to_linear,
+    convert::linear_to_rec2020
+);
+
+rgb_space!(
+    /// Adobe RGB (1998), with a pure power-law transfer function.
+    AdobeRgb,
+    "a98-rgb",
+    convert::A98_PRIMARIES,
+    WhitePoint::D65,
+    convert::a98_to_linear,
+    convert::linear_to_a98
+);
+
+rgb_space!(
+    /// ProPhoto RGB (ROMM RGB), a very wide space with a D50 white.
+    ProPhotoRgb,
+    "prophoto-rgb",
+    convert::PROPHOTO_PRIMARIES,
+    WhitePoint::D50,
+    convert::prophoto_to_linear,
+    convert::linear_to_prophoto
+);
+
+rgb_space!(
+    /// ACEScg: the ACES AP1 primaries with linear encoding, used for
+    /// rendering and compositing in film pipelines.
+    AcesCg,
+    "acescg",
+    convert::AP1_PRIMARIES,
+    WhitePoint::from_chromaticity(convert::ACES_WHITE_XY.0, convert::ACES_WHITE_XY.1),
+    |c| c,
+    |c| c
+);
+
+fn xyz_matrices(primaries: [(f64, f64); 3], white: WhitePoint) -> (Mat3, Mat3) {
+    let to_xyz = convert::rgb_to_xyz_matrix(primaries, white.to_array());
+    (to_xyz, convert::invert(&to_xyz))
+}
+
+/// A colour in the RGB space `S`, with encoded channels as `f64`.
+///
+/// Channels are nominally in `0.0..=1.0`. Values outside that range are kept,
+/// since a colour converted from a wider space may lie outside this one's
+/// gamut.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct WideRgb<S> {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    space: PhantomData<S>,
+}
+
+impl<S: RgbSpace> WideRgb<S> {
+    pub fn new(red: f64, green: f64, blue: f64) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            space: PhantomData,
+        }
+    }
+
+    pub(crate) fn from_array([red, green, blue]: Vec3) -> Self {
+        Self::new(red, green, blue)
+    }
+
+    pub(crate) fn to_array(self) -> Vec3 {
+        [self.red, self.green, self.blue]
+    }
+
+    /// Builds a colour from linear-light channels.
+    pub fn from_linear(linear: [f64; 3]) -> Self {
+        Self::from_array(linear.map(S::from_linear))
+    }
+
+    /// The linear-light channels.
+    pub fn to_linear(self) -> [f64; 3] {
+        self.to_array().map(S::to_linear)
+    }
+
+    /// Converts to XYZ relative to `white`, adapting from the space's own
+    /// white point with the Bradford transform if they differ.
+    pub fn to_xyz(self, white: WhitePoint) -> Xyz {
+        let (to_xyz, _) = S::xyz_matrices();
+        let xyz = Xyz::from_array(convert::mul(&to_xyz, self.to_linear()));
+        xyz.adapt(S::WHITE, white)
+    }
+
+    /// Converts from XYZ relative to `white`.
+    pub fn from_xyz(xyz: Xyz, white: WhitePoint) -> Self {
+        let xyz = xyz.adapt(white, S::WHITE);
+        let (_, from_xyz) = S::xyz_matrices();
+        Self::from_linear(convert::mul(&from_xyz, xyz.to_array()))
+    }
+
+    /// Converts to another RGB space through XYZ, adapting between their
+    /// white points. Colours outside the other space's gamut give channels
+    /// outside `0.0..=1.0`.
+    pub fn convert<T: RgbSpace>(self) -> WideRgb<T> {
+        WideRgb::from_xyz(self.to_xyz(T::WHITE), T::WHITE)
+    }
+
+    /// Converts to an 8-bit sRGB colour, mapping anything outside the sRGB
+    /// gamut into it with [`GamutMapping::Css`].
+    pub fn to_rgb(self) -> RGB {
+        RGB::from_unit(self.convert::<Srgb>().to_gamut(GamutMapping::Css).to_array())
+    }
+}
+
+impl<S: RgbSpace> From<RGB> for WideRgb<S> {
+    fn from(rgb: RGB) -> Self {
+        WideRgb::<Srgb>::from_array(rgb.to_unit()).convert()
+    }
+}
+
+impl<S: RgbSpace> From<WideRgb<S>> for RGB {
+    /// Converts to sRGB, mapping anything outside the sRGB gamut into it.
+    fn from(colour: WideRgb<S>) -> Self {
+        colour.to_rgb()
+    }
+}
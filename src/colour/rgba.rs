@@ -0,0 +1,185 @@
+use crate::colour::{
+    css::parse_css_colour,
+    parse::{parse_hex, ColourParseError},
+    RGB,
+};
+
+use std::{
+    convert::TryFrom,
+    fmt,
+    str::FromStr,
+};
+
+/// An sRGB colour with straight alpha: the colour channels are stored as
+/// they would appear if the colour were opaque, and `a` is how opaque it is,
+/// from `0` for transparent to `255` for opaque.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Rgba {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+impl Rgba {
+    /// Transparent black, as CSS `transparent`.
+    pub const TRANSPARENT: Self = Self::new(0, 0, 0, 0);
+
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub fn from_rgb(rgb: RGB, a: u8) -> Self {
+        let (r, g, b) = rgb.to_tuple();
+        Self::new(r, g, b, a)
+    }
+
+    /// Parses a hex colour such as `#ff880080`, `#f808` or `#ff8800`, which
+    /// is opaque.
+    pub fn from_hex_string<S: AsRef<str>>(hex_string: S) -> Result<Self, ColourParseError> {
+        let [r, g, b, a] = parse_hex(hex_string.as_ref())?;
+        Ok(Self::new(r, g, b, a))
+    }
+
+    /// Parses any CSS colour value, such as `#f808`, `rgb(255 136 0 / 50%)`
+    /// or `transparent`, mapping colours outside the sRGB gamut into it as CSS
+    /// does.
+    pub fn from_css_string<S: AsRef<str>>(css: S) -> Result<Self, ColourParseError> {
+        Ok(parse_css_colour(css.as_ref())?.to_rgba())
+    }
+
+    /// The colour without its alpha.
+    pub fn rgb(&self) -> RGB {
+        RGB::new(self.r, self.g, self.b)
+    }
+
+    pub fn alpha(&self) -> u8 {
+        self.a
+    }
+
+    pub fn with_alpha(mut self, a: u8) -> Self {
+        self.a = a;
+        self
+    }
+
+    pub fn is_opaque(&self) -> bool {
+        self.a == u8::MAX
+    }
+
+    pub fn to_tuple(&self) -> (u8, u8, u8, u8) {
+        (self.r, self.g, self.b, self.a)
+    }
+
+    /// The channels, alpha last, scaled to `0.0..=1.0`.
+    pub fn to_unit(&self) -> [f64; 4] {
+        [self.r, self.g, self.b, self.a].map(|c| c as f64 / 255.0)
+    }
+
+    /// Builds a colour from channels in `0.0..=1.0`, alpha last, clipping
+    /// anything outside that range and rounding to the nearest byte.
+    pub fn from_unit([r, g, b, a]: [f64; 4]) -> Self {
+        let channel = |c: f64| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self::new(channel(r), channel(g), channel(b), channel(a))
+    }
+
+    /// The colour with each channel multiplied by alpha.
+    pub fn premultiply(&self) -> PremultipliedRgba {
+        let scale = |c: u8| ((c as u32 * self.a as u32 + 127) / 255) as u8;
+        PremultipliedRgba {
+            r: scale(self.r),
+            g: scale(self.g),
+            b: scale(self.b),
+            a: self.a,
+        }
+    }
+}
+
+/// An sRGB colour with premultiplied alpha: each colour channel has already
+/// been multiplied by `a`, so no channel exceeds it. This is the form
+/// compositing and filtering work in.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PremultipliedRgba {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+impl PremultipliedRgba {
+    /// Builds a premultiplied colour, lowering any colour channel that
+    /// exceeds alpha to it.
+    pub fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self {
+            r: r.min(a),
+            g: g.min(a),
+            b: b.min(a),
+            a,
+        }
+    }
+
+    pub fn alpha(&self) -> u8 {
+        self.a
+    }
+
+    pub fn to_tuple(&self) -> (u8, u8, u8, u8) {
+        (self.r, self.g, self.b, self.a)
+    }
+
+    /// Divides alpha back out. A fully transparent colour has no colour left
+    /// to recover, and becomes transparent black.
+    pub fn unpremultiply(&self) -> Rgba {
+        if self.a == 0 {
+            return Rgba::TRANSPARENT;
+        }
+        let a = self.a as u32;
+        let scale = |c: u8| ((c as u32 * 255 + a / 2) / a).min(255) as u8;
+        Rgba::new(scale(self.r), scale(self.g), scale(self.b), self.a)
+    }
+}
+
+impl From<RGB> for Rgba {
+    fn from(rgb: RGB) -> Self {
+        Self::from_rgb(rgb, u8::MAX)
+    }
+}
+
+/// Drops alpha.
+impl From<Rgba> for RGB {
+    fn from(rgba: Rgba) -> Self {
+        rgba.rgb()
+    }
+}
+
+impl From<Rgba> for PremultipliedRgba {
+    fn from(rgba: Rgba) -> Self {
+        rgba.premultiply()
+    }
+}
+
+impl From<PremultipliedRgba> for Rgba {
+    fn from(rgba: PremultipliedRgba) -> Self {
+        rgba.unpremultiply()
+    }
+}
+
+impl FromStr for Rgba {
+    type Err = ColourParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex_string(s)
+    }
+}
+
+impl TryFrom<&str> for Rgba {
+    type Error = ColourParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_hex_string(s)
+    }
+}
+
+impl fmt::Display for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+    }
+}
@@ -0,0 +1,94 @@
+//! The CIE 1931 XYZ colour space and reference white points.
+
+use crate::colour::{convert, gamut, Lab, LinearRgb, RGB};
+
+/// A reference white, as XYZ tristimulus values normalised to `y = 1`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WhitePoint {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl WhitePoint {
+    /// CIE standard illuminant D50, as used by CSS `lab()` and `lch()`.
+    pub const D50: WhitePoint = WhitePoint::from_chromaticity(convert::D50_XY.0, convert::D50_XY.1);
+    /// CIE standard illuminant D65, the white point of sRGB.
+    pub const D65: WhitePoint = WhitePoint::from_chromaticity(convert::D65_XY.0, convert::D65_XY.1);
+
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Builds a white point from its `x`, `y` chromaticity coordinates.
+    pub const fn from_chromaticity(x: f64, y: f64) -> Self {
+        Self::new(x / y, 1.0, (1.0 - x - y) / y)
+    }
+
+    pub(crate) fn to_array(self) -> convert::Vec3 {
+        [self.x, self.y, self.z]
+    }
+}
+
+/// A colour as CIE 1931 XYZ tristimulus values, scaled so that the white
+/// point has `y = 1`.
+///
+/// Conversions to and from [`RGB`] go through linear-light sRGB and are
+/// relative to [`WhitePoint::D65`]; use [`Xyz::adapt`] to move between white
+/// points.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Xyz {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Xyz {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub(crate) fn from_array([x, y, z]: convert::Vec3) -> Self {
+        Self::new(x, y, z)
+    }
+
+    pub(crate) fn to_array(self) -> convert::Vec3 {
+        [self.x, self.y, self.z]
+    }
+
+    /// Converts values relative to the `from` white point into ones relative
+    /// to `to`, using the Bradford chromatic adaptation transform.
+    pub fn adapt(self, from: WhitePoint, to: WhitePoint) -> Self {
+        if from == to {
+            return self;
+        }
+        let matrix = convert::bradford(from.to_array(), to.to_array());
+        Self::from_array(convert::mul(&matrix, self.to_array()))
+    }
+
+    /// Converts to CIE Lab, taking these values to be relative to `white`.
+    pub fn to_lab(self, white: WhitePoint) -> Lab {
+        Lab::from_array(convert::xyz_to_lab(self.to_array(), white.to_array()))
+    }
+
+    /// Converts from CIE Lab relative to `white`.
+    pub fn from_lab(lab: Lab, white: WhitePoint) -> Self {
+        Self::from_array(convert::lab_to_xyz(lab.to_array(), white.to_array()))
+    }
+}
+
+impl From<RGB> for Xyz {
+    fn from(rgb: RGB) -> Self {
+        let linear = LinearRgb::from(rgb).to_array();
+        Self::from_array(convert::linear_srgb_to_xyz(linear))
+    }
+}
+
+impl From<Xyz> for RGB {
+    /// Converts D65-relative values to sRGB, mapping anything outside the
+    /// sRGB gamut into it with
+    /// [`GamutMapping::Css`](crate::colour::GamutMapping::Css).
+    fn from(xyz: Xyz) -> Self {
+        gamut::linear_srgb_to_rgb(convert::xyz_to_linear_srgb(xyz.to_array()))
+    }
+}
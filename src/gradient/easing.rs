@@ -0,0 +1,129 @@
+//! Easing curves for the transition between colour stops.
+
+/// A timing curve reshaping the progress from one colour stop to the next.
+///
+/// The named curves follow CSS Easing Functions Level 1, so a segment eased
+/// with [`Easing::EaseInOut`] blends like a CSS transition with
+/// `ease-in-out`.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Easing {
+    /// Even progress; the CSS gradient behaviour.
+    #[default]
+    Linear,
+    /// CSS `ease`, `cubic-bezier(0.25, 0.1, 0.25, 1)`.
+    Ease,
+    /// CSS `ease-in`, `cubic-bezier(0.42, 0, 1, 1)`.
+    EaseIn,
+    /// CSS `ease-out`, `cubic-bezier(0, 0, 0.58, 1)`.
+    EaseOut,
+    /// CSS `ease-in-out`, `cubic-bezier(0.42, 0, 0.58, 1)`.
+    EaseInOut,
+    /// The Hermite curve `3t² − 2t³`, flat at both ends.
+    SmoothStep,
+    /// CSS `steps(n, position)`: a staircase of `n` equal steps.
+    Steps(u32, StepPosition),
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`. The `x` values are clamped to
+    /// `0.0..=1.0`; the `y` values may overshoot.
+    CubicBezier(f64, f64, f64, f64),
+}
+
+/// Where the jumps of [`Easing::Steps`] fall, as in CSS.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum StepPosition {
+    /// The first jump happens at the very start.
+    JumpStart,
+    /// The last jump happens at the very end.
+    #[default]
+    JumpEnd,
+    /// No jump at either end; the steps hold `0.0` and `1.0` for a step each.
+    JumpNone,
+    /// Jumps at both the start and the end.
+    JumpBoth,
+}
+
+impl Easing {
+    /// Maps progress `t` in `0.0..=1.0` to eased progress.
+    pub fn apply(&self, t: f64) -> f64 {
+        match *self {
+            Self::Linear => t,
+            Self::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0, t),
+            Self::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Self::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Self::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Self::SmoothStep => t * t * (3.0 - 2.0 * t),
+            Self::Steps(steps, position) => self::steps(steps, position, t),
+            Self::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+        }
+    }
+}
+
+fn steps(steps: u32, position: StepPosition, t: f64) -> f64 {
+    let steps = steps as f64;
+    let jumps = match position {
+        StepPosition::JumpStart | StepPosition::JumpEnd => steps,
+        StepPosition::JumpNone => steps - 1.0,
+        StepPosition::JumpBoth => steps + 1.0,
+    };
+    if jumps < 1.0 {
+        // `steps(0)` and `steps(1, jump-none)` are invalid in CSS; treat
+        // them as no easing at all.
+        return t;
+    }
+
+    let mut step = (t * steps).floor();
+    if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+        step += 1.0;
+    }
+    step.clamp(0.0, jumps) / jumps
+}
+
+/// Evaluates the CSS cubic Bézier timing function through `(0, 0)`,
+/// `(x1, y1)`, `(x2, y2)` and `(1, 1)` at `x = t`.
+fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, t: f64) -> f64 {
+    let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+    if t <= 0.0 || t >= 1.0 {
+        return t.clamp(0.0, 1.0);
+    }
+
+    // Coefficients of the polynomial form a·s³ + b·s² + c·s.
+    let coefficients = |p1: f64, p2: f64| {
+        let c = 3.0 * p1;
+        let b = 3.0 * (p2 - p1) - c;
+        (1.0 - c - b, b, c)
+    };
+    let (ax, bx, cx) = coefficients(x1, x2);
+    let (ay, by, cy) = coefficients(y1, y2);
+    let x = |s: f64| ((ax * s + bx) * s + cx) * s;
+    let dx = |s: f64| (3.0 * ax * s + 2.0 * bx) * s + cx;
+
+    // Newton's method converges quickly for most curves; fall back to
+    // bisection, which always works because x(s) is monotonic.
+    let mut s = t;
+    for _ in 0..8 {
+        let error = x(s) - t;
+        if error.abs() < 1e-9 {
+            return ((ay * s + by) * s + cy) * s;
+        }
+        let slope = dx(s);
+        if slope.abs() < 1e-9 {
+            break;
+        }
+        s -= error / slope;
+    }
+
+    let (mut lo, mut hi) = (0.0, 1.0);
+    s = t;
+    for _ in 0..64 {
+        let value = x(s);
+        if (value - t).abs() < 1e-9 {
+            break;
+        }
+        if value < t {
+            lo = s;
+        } else {
+            hi = s;
+        }
+        s = (lo + hi) / 2.0;
+    }
+    ((ay * s + by) * s + cy) * s
+}
@@ -0,0 +1,227 @@
+//! Two-dimensional gradients: four-corner bilinear fills and Coons-patch
+//! meshes.
+
+use crate::{
+    colour::{convert::Vec3, GamutMapping, RGB},
+    gradient::{to_gamut, GradientError, InterpolationSpace},
+};
+
+/// A point in pixels, measured from the top-left corner of the image.
+pub type Point = (f64, f64);
+
+/// Mixes four corner colours, already in `space`, at `(u, v)`: across the
+/// top and bottom edges first, then between them.
+fn bilinear(space: InterpolationSpace, corners: &[Vec3; 4], u: f64, v: f64) -> Vec3 {
+    let [top_left, top_right, bottom_right, bottom_left] = *corners;
+    let top = space.lerp(top_left, top_right, u);
+    let bottom = space.lerp(bottom_left, bottom_right, u);
+    space.lerp(top, bottom, v)
+}
+
+fn encode(space: InterpolationSpace, colours: [RGB; 4]) -> [Vec3; 4] {
+    colours.map(|c| space.encode(c.to_unit()))
+}
+
+/// A gradient filling a rectangle from a colour at each corner.
+pub struct BilinearGradient {
+    corners: [RGB; 4],
+    encoded: [Vec3; 4],
+    space: InterpolationSpace,
+}
+
+impl BilinearGradient {
+    pub fn new(
+        top_left: RGB,
+        top_right: RGB,
+        bottom_left: RGB,
+        bottom_right: RGB,
+        space: InterpolationSpace,
+    ) -> Self {
+        let corners = [top_left, top_right, bottom_right, bottom_left];
+        Self {
+            corners,
+            encoded: encode(space, corners),
+            space,
+        }
+    }
+
+    /// The corner colours, clockwise from the top left.
+    pub fn corners(&self) -> [RGB; 4] {
+        self.corners
+    }
+
+    pub fn space(&self) -> InterpolationSpace {
+        self.space
+    }
+
+    /// The colour at `(u, v)`, where `(0.0, 0.0)` is the top-left corner and
+    /// `(1.0, 1.0)` the bottom-right. Coordinates are clamped to the square,
+    /// and colours outside the sRGB gamut mapped into it.
+    pub fn sample(&self, u: f64, v: f64) -> RGB {
+        RGB::from_unit(to_gamut(self.sample_unit(u, v), GamutMapping::Css))
+    }
+
+    pub(crate) fn sample_unit(&self, u: f64, v: f64) -> Vec3 {
+        let (u, v) = (u.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+        self.space.decode(bilinear(self.space, &self.encoded, u, v))
+    }
+}
+
+/// A Coons patch: a region bounded by four cubic Bézier curves, coloured by
+/// mixing the colours at its corners.
+///
+/// Each edge is given by its four control points. The top and bottom edges
+/// run left to right and the left and right edges top to bottom, so the
+/// curves meet at the corners: `top[0] == left[0]`, `top[3] == right[0]`,
+/// `bottom[0] == left[3]` and `bottom[3] == right[3]`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CoonsPatch {
+    pub top: [Point; 4],
+    pub right: [Point; 4],
+    pub bottom: [Point; 4],
+    pub left: [Point; 4],
+    /// The corner colours, clockwise from the top left.
+    pub colours: [RGB; 4],
+}
+
+impl CoonsPatch {
+    /// A patch with straight edges between four corners, given clockwise
+    /// from the top left, with their colours.
+    pub fn quad(corners: [Point; 4], colours: [RGB; 4]) -> Self {
+        let [top_left, top_right, bottom_right, bottom_left] = corners;
+        let line = |a: Point, b: Point| {
+            let at = |t: f64| (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+            [a, at(1.0 / 3.0), at(2.0 / 3.0), b]
+        };
+        Self {
+            top: line(top_left, top_right),
+            right: line(top_right, bottom_right),
+            bottom: line(bottom_left, bottom_right),
+            left: line(top_left, bottom_left),
+            colours,
+        }
+    }
+
+    /// The point at `(u, v)` on the patch surface, where `u` runs along the
+    /// top and bottom edges and `v` along the left and right ones.
+    pub fn point(&self, u: f64, v: f64) -> Point {
+        let (top, bottom) = (bezier(&self.top, u), bezier(&self.bottom, u));
+        let (left, right) = (bezier(&self.left, v), bezier(&self.right, v));
+        let corners = [self.top[0], self.top[3], self.bottom[3], self.bottom[0]];
+
+        let ruled = |a: f64, b: f64, t: f64| a + (b - a) * t;
+        let corner = |axis: fn(Point) -> f64| {
+            let top = ruled(axis(corners[0]), axis(corners[1]), u);
+            let bottom = ruled(axis(corners[3]), axis(corners[2]), u);
+            ruled(top, bottom, v)
+        };
+
+        let x = ruled(top.0, bottom.0, v) + ruled(left.0, right.0, u) - corner(|p| p.0);
+        let y = ruled(top.1, bottom.1, v) + ruled(left.1, right.1, u) - corner(|p| p.1);
+        (x, y)
+    }
+
+    /// A rough size in pixels: the longest control polygon of the four edges.
+    pub(crate) fn extent(&self) -> f64 {
+        [&self.top, &self.right, &self.bottom, &self.left]
+            .iter()
+            .map(|curve| {
+                curve
+                    .windows(2)
+                    .map(|w| (w[1].0 - w[0].0).hypot(w[1].1 - w[0].1))
+                    .sum::<f64>()
+            })
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Evaluates a cubic Bézier curve at `t`.
+fn bezier(points: &[Point; 4], t: f64) -> Point {
+    let s = 1.0 - t;
+    let weights = [s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t];
+    points
+        .iter()
+        .zip(weights)
+        .fold((0.0, 0.0), |acc, (p, w)| (acc.0 + p.0 * w, acc.1 + p.1 * w))
+}
+
+/// A gradient made of Coons patches, each coloured from its corners. Later
+/// patches are drawn over earlier ones where they overlap.
+pub struct MeshGradient {
+    patches: Vec<CoonsPatch>,
+    encoded: Vec<[Vec3; 4]>,
+    space: InterpolationSpace,
+}
+
+impl MeshGradient {
+    pub fn new(patches: Vec<CoonsPatch>, space: InterpolationSpace) -> Self {
+        Self {
+            encoded: patches.iter().map(|p| encode(space, p.colours)).collect(),
+            patches,
+            space,
+        }
+    }
+
+    /// A regular grid of `columns` by `rows` patches covering a `width` by
+    /// `height` pixel rectangle, coloured from the `(columns + 1) * (rows + 1)`
+    /// grid vertices given row by row.
+    pub fn grid(
+        columns: usize,
+        rows: usize,
+        colours: &[RGB],
+        (width, height): (f64, f64),
+        space: InterpolationSpace,
+    ) -> Result<Self, GradientError> {
+        if columns == 0 || rows == 0 {
+            return Err(GradientError::EmptyMesh);
+        }
+        let expected = (columns + 1) * (rows + 1);
+        if colours.len() != expected {
+            return Err(GradientError::MeshSize {
+                expected,
+                found: colours.len(),
+            });
+        }
+
+        let vertex = |col: usize, row: usize| {
+            let point = (
+                width * col as f64 / columns as f64,
+                height * row as f64 / rows as f64,
+            );
+            (point, colours[row * (columns + 1) + col])
+        };
+
+        let mut patches = Vec::with_capacity(columns * rows);
+        for row in 0..rows {
+            for col in 0..columns {
+                let corners = [
+                    vertex(col, row),
+                    vertex(col + 1, row),
+                    vertex(col + 1, row + 1),
+                    vertex(col, row + 1),
+                ];
+                patches.push(CoonsPatch::quad(
+                    corners.map(|(point, _)| point),
+                    corners.map(|(_, colour)| colour),
+                ));
+            }
+        }
+
+        Ok(Self::new(patches, space))
+    }
+
+    pub fn patches(&self) -> &[CoonsPatch] {
+        &self.patches
+    }
+
+    pub fn space(&self) -> InterpolationSpace {
+        self.space
+    }
+
+    /// The colour at `(u, v)` within the patch at `index`, as sRGB
+    /// channels.
+    pub(crate) fn sample_unit(&self, index: usize, u: f64, v: f64) -> Vec3 {
+        self.space
+            .decode(bilinear(self.space, &self.encoded[index], u, v))
+    }
+}
@@ -0,0 +1,251 @@
+//! Gradients through any number of colour stops.
+
+mod easing;
+mod mesh;
+mod space;
+mod spread;
+mod stop;
+
+pub use easing::{Easing, StepPosition};
+pub use mesh::{BilinearGradient, CoonsPatch, MeshGradient, Point};
+pub use space::{HueInterpolation, InterpolationSpace};
+pub use spread::Spread;
+pub use stop::ColourStop;
+
+use crate::colour::{convert::Vec3, Component, GamutMapping, Rgb, Rgba, Srgb, WideRgb, RGB};
+
+use std::{error::Error, fmt};
+
+pub struct Gradient {
+    gradient: Vec<RGB>,
+    stops: Vec<ColourStop>,
+    encoded: Vec<Vec3>,
+    steps: usize, 
+    space: InterpolationSpace,
+    spread: Spread,
+}
+
+impl Gradient {
+    /// A gradient from `start` to `end`, the same as stops at `0.0` and
+    /// `1.0`.
+    pub fn new<T: Component>(start: Rgb<T>, end: Rgb<T>, steps: usize, space: InterpolationSpace) -> Self {
+        Self::from_stops(vec![(0.0, start), (1.0, end)], steps, space)
+            .expect("two finite stops are always valid")
+    }
+
+    /// A gradient through a list of stops, following CSS `linear-gradient`:
+    /// the stops are taken in order, any stop or hint placed before an
+    /// earlier one is moved up to it, and two stops at the same position make
+    /// a hard edge. The first and last colours extend beyond the first and
+    /// last stops.
+    pub fn from_stops<I>(stops: I, steps: usize, space: InterpolationSpace) -> Result<Self, GradientError>
+    where
+        I: IntoIterator,
+        I::Item: Into<ColourStop>,
+    {
+        let mut stops: Vec<ColourStop> = stops.into_iter().map(Into::into).collect();
+        if stops.is_empty() {
+            return Err(GradientError::NoStops);
+        }
+        for (index, stop) in stops.iter().enumerate() {
+            if !stop.position.is_finite() {
+                return Err(GradientError::InvalidPosition {
+                    index,
+                    position: stop.position,
+                });
+            }
+            match stop.hint {
+                Some(position) if !position.is_finite() => {
+                    return Err(GradientError::InvalidHint { index, position })
+                }
+                _ => {}
+            }
+            if stop.alpha.is_nan() {
+                return Err(GradientError::InvalidAlpha { index });
+            }
+        }
+        for stop in &mut stops {
+            stop.alpha = stop.alpha.clamp(0.0, 1.0);
+        }
+        stop::fix_up(&mut stops);
+
+        Ok(Self {
+            gradient: Self::generate_gradient(&stops, steps, space),
+            encoded: encode(&stops, space),
+            stops,
+            steps, 
+            space,
+            spread: Spread::default(),
+        })
+    }
+
+    /// Sets how positions outside `0.0..=1.0` are handled when sampling.
+    pub fn with_spread(mut self, spread: Spread) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// The colour of the first stop, rounded to 8 bits.
+    pub fn start(&self) -> RGB {
+        self.stops[0].colour.convert()
+    }
+
+    /// The colour of the last stop, rounded to 8 bits.
+    pub fn end(&self) -> RGB {
+        self.stops[self.stops.len() - 1].colour.convert()
+    }
+
+    /// The stops, with positions after the CSS fix-up.
+    pub fn stops(&self) -> &[ColourStop] {
+        &self.stops
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn space(&self) -> InterpolationSpace {
+        self.space
+    }
+
+    pub fn spread(&self) -> Spread {
+        self.spread
+    }
+
+    /// The colour at position `t` along the gradient, where `0.0` is the
+    /// start and `1.0` the end. Positions outside that range are brought
+    /// back into it according to the gradient's [`Spread`]. Alpha is
+    /// dropped; see [`Gradient::sample_rgba`].
+    pub fn sample(&self, t: f64) -> RGB {
+        self.sample_as(t)
+    }
+
+    /// The colour at position `t`, as [`Gradient::sample`], with channels
+    /// of any type. Colours mixed outside the sRGB gamut, as Lab, LCh, Oklab
+    /// and Oklch can give, are brought inside with [`GamutMapping::Css`] for
+    /// integer channels; float channels keep them as they are.
+    pub fn sample_as<T: Component>(&self, t: f64) -> Rgb<T> {
+        let (colour, _) = self.sample_unit(t);
+        match T::LEVELS {
+            Some(_) => Rgb::from_unit(to_gamut(colour, GamutMapping::Css)),
+            None => Rgb::from_unit(colour),
+        }
+    }
+
+    /// The colour and opacity at position `t` along the gradient.
+    pub fn sample_rgba(&self, t: f64) -> Rgba {
+        let (colour, alpha) = self.sample_unit(t);
+        let [r, g, b] = to_gamut(colour, GamutMapping::Css);
+        Rgba::from_unit([r, g, b, alpha])
+    }
+
+    /// `n` colours spread evenly along the gradient, from its start to its
+    /// end inclusive.
+    pub fn sample_n(&self, n: usize) -> Vec<RGB> {
+        let last = n.saturating_sub(1).max(1) as f64;
+        (0..n).map(|idx| self.sample(idx as f64 / last)).collect()
+    }
+
+    /// The colour at position `t` as unclipped sRGB channels, nominally in
+    /// `0.0..=1.0`, and its opacity.
+    pub(crate) fn sample_unit(&self, t: f64) -> (Vec3, f64) {
+        let t = self.spread.apply(t);
+        let (colour, alpha) = interpolate(&self.stops, &self.encoded, self.space, t);
+        (self.space.decode(colour), alpha)
+    }
+    
+    /// Builds `steps` colours spread evenly from position `0.0` to `1.0`
+    /// along a gradient through `stops`, mixed in `space` and mapped into
+    /// the sRGB gamut. The stops must be in order and not empty.
+    pub fn generate_gradient(stops: &[ColourStop], steps: usize, space: InterpolationSpace) -> Vec<RGB> {
+        let mut gradient = vec![RGB::default(); steps]; 
+        let encoded = encode(stops, space);
+        let last = steps.saturating_sub(1).max(1) as f64;
+
+        for (idx, c) in gradient.iter_mut().enumerate() {
+            let t: f64 = idx as f64 / last; 
+            *c = RGB::from_unit(to_gamut(space.decode(interpolate(stops, &encoded, space, t).0), GamutMapping::Css)); 
+        }
+
+        gradient 
+    }
+}
+
+/// Brings sRGB channels decoded from an interpolation space into the sRGB
+/// gamut.
+pub(crate) fn to_gamut(colour: Vec3, strategy: GamutMapping) -> Vec3 {
+    WideRgb::<Srgb>::from_array(colour).to_gamut(strategy).to_array()
+}
+
+/// Converts each stop's colour into the interpolation space.
+fn encode(stops: &[ColourStop], space: InterpolationSpace) -> Vec<Vec3> {
+    stops
+        .iter()
+        .map(|stop| space.encode(stop.colour.to_unit()))
+        .collect()
+}
+
+/// The colour at `t` along `stops`, in the interpolation space, and its
+/// opacity.
+fn interpolate(stops: &[ColourStop], encoded: &[Vec3], space: InterpolationSpace, t: f64) -> (Vec3, f64) {
+    match stop::segment(stops, t) {
+        Ok((i, local)) => {
+            let progress = stops[i].transition(&stops[i + 1], local);
+            space.lerp_alpha(encoded[i], stops[i].alpha, encoded[i + 1], stops[i + 1].alpha, progress)
+        }
+        Err(i) => (encoded[i], stops[i].alpha),
+    }
+}
+
+impl IntoIterator for Gradient {
+    type Item = RGB; 
+    type IntoIter = std::vec::IntoIter<Self::Item>; 
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.gradient.into_iter() 
+    }
+}
+
+/// The ways building a gradient can fail.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradientError {
+    /// No colour stops were given.
+    NoStops,
+    /// A stop's position was infinite or NaN.
+    InvalidPosition { index: usize, position: f64 },
+    /// A stop's colour hint was infinite or NaN.
+    InvalidHint { index: usize, position: f64 },
+    /// A stop's alpha was NaN.
+    InvalidAlpha { index: usize },
+    /// A mesh grid had no columns or no rows.
+    EmptyMesh,
+    /// A mesh grid was given the wrong number of colours for its size.
+    MeshSize { expected: usize, found: usize },
+}
+
+impl fmt::Display for GradientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NoStops => write!(f, "a gradient needs at least one colour stop"),
+            Self::InvalidPosition { index, position } => write!(
+                f,
+                "colour stop {} has invalid position {}",
+                index, position
+            ),
+            Self::InvalidHint { index, position } => write!(
+                f,
+                "colour stop {} has invalid hint position {}",
+                index, position
+            ),
+            Self::InvalidAlpha { index } => write!(f, "colour stop {} has invalid alpha", index),
+            Self::EmptyMesh => write!(f, "a mesh grid needs at least one column and row"),
+            Self::MeshSize { expected, found } => write!(
+                f,
+                "mesh grid needs {} colours but {} were given",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl Error for GradientError {}
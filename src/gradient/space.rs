@@ -0,0 +1,169 @@
+//! Colour spaces that gradients can interpolate in.
+
+use crate::colour::convert::{self, Vec3};
+
+/// The colour space a [`Gradient`](super::Gradient) mixes its colours in.
+///
+/// The same two endpoints give quite different gradients depending on the
+/// space. Mixing gamma-encoded sRGB is what the web did for years and tends
+/// to give dull, dark midpoints; the perceptual spaces, [`Oklab`] in
+/// particular, give even steps in lightness and keep midpoints vivid. The
+/// cylindrical spaces carry a [`HueInterpolation`] choosing which way around
+/// the colour wheel their hue travels.
+///
+/// [`Oklab`]: InterpolationSpace::Oklab
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum InterpolationSpace {
+    /// Gamma-encoded sRGB, interpolating the channels directly.
+    #[default]
+    Srgb,
+    /// Linear-light sRGB.
+    LinearSrgb,
+    /// HSL, interpolating hue around the colour wheel.
+    Hsl(HueInterpolation),
+    /// CIE Lab relative to D50, as in CSS.
+    Lab,
+    /// CIE LCh relative to D50, as in CSS.
+    Lch(HueInterpolation),
+    /// Oklab, the default interpolation space in CSS.
+    Oklab,
+    /// Oklch.
+    Oklch(HueInterpolation),
+}
+
+/// Which way around the colour wheel hue is interpolated, following the
+/// CSS `<hue-interpolation-method>`.
+///
+/// Hues are taken in `0.0..360.0`, and `Δ` below is the end hue minus the
+/// start hue.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum HueInterpolation {
+    /// The shorter arc, never more than 180°.
+    #[default]
+    Shorter,
+    /// The longer arc, never less than 180°.
+    Longer,
+    /// Always with increasing hue, going clockwise when `Δ` is negative.
+    Increasing,
+    /// Always with decreasing hue, going anticlockwise when `Δ` is positive.
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// Adjusts a pair of hues so that plain linear interpolation between
+    /// them travels the chosen way around the wheel.
+    fn fix_up(self, mut from: f64, mut to: f64) -> (f64, f64) {
+        let delta = to - from;
+        match self {
+            Self::Shorter if delta > 180.0 => from += 360.0,
+            Self::Shorter if delta < -180.0 => to += 360.0,
+            Self::Longer if 0.0 < delta && delta < 180.0 => from += 360.0,
+            Self::Longer if -180.0 < delta && delta <= 0.0 => to += 360.0,
+            Self::Increasing if delta < 0.0 => to += 360.0,
+            Self::Decreasing if delta > 0.0 => from += 360.0,
+            _ => {}
+        }
+        (from, to)
+    }
+}
+
+impl InterpolationSpace {
+    /// For cylindrical spaces, the index of the hue component, the largest
+    /// chroma (or saturation) at which the hue counts as powerless, and the
+    /// hue interpolation method.
+    fn hue(self) -> Option<(usize, f64, HueInterpolation)> {
+        match self {
+            Self::Hsl(method) => Some((0, 1e-9, method)),
+            Self::Lch(method) => Some((2, 0.0015, method)),
+            Self::Oklch(method) => Some((2, 0.000_004, method)),
+            _ => None,
+        }
+    }
+
+    /// Converts gamma-encoded sRGB channels in `0.0..=1.0` to this space.
+    pub(crate) fn encode(self, srgb: Vec3) -> Vec3 {
+        let linear = || srgb.map(convert::srgb_to_linear);
+        let lab = || {
+            let xyz = convert::d65_to_d50(convert::linear_srgb_to_xyz(linear()));
+            convert::xyz_to_lab(xyz, convert::d50())
+        };
+        let oklab = || convert::linear_srgb_to_oklab(linear());
+
+        match self {
+            Self::Srgb => srgb,
+            Self::LinearSrgb => linear(),
+            Self::Hsl(_) => convert::srgb_to_hsl(srgb),
+            Self::Lab => lab(),
+            Self::Lch(_) => convert::rect_to_polar(lab()),
+            Self::Oklab => oklab(),
+            Self::Oklch(_) => convert::rect_to_polar(oklab()),
+        }
+    }
+
+    /// Converts from this space back to gamma-encoded sRGB channels. Colours
+    /// outside the sRGB gamut give channels outside `0.0..=1.0`.
+    pub(crate) fn decode(self, coords: Vec3) -> Vec3 {
+        let from_lab = |lab: Vec3| {
+            let xyz = convert::d50_to_d65(convert::lab_to_xyz(lab, convert::d50()));
+            convert::xyz_to_linear_srgb(xyz).map(convert::linear_to_srgb)
+        };
+        let from_oklab =
+            |lab: Vec3| convert::oklab_to_linear_srgb(lab).map(convert::linear_to_srgb);
+
+        match self {
+            Self::Srgb => coords,
+            Self::LinearSrgb => coords.map(convert::linear_to_srgb),
+            Self::Hsl(_) => convert::hsl_to_srgb(coords[0], coords[1], coords[2]),
+            Self::Lab => from_lab(coords),
+            Self::Lch(_) => from_lab(convert::polar_to_rect(coords)),
+            Self::Oklab => from_oklab(coords),
+            Self::Oklch(_) => from_oklab(convert::polar_to_rect(coords)),
+        }
+    }
+
+    /// Interpolates between two colours already converted to this space,
+    /// where `t = 0.0` gives `a` and `t = 1.0` gives `b`.
+    pub(crate) fn lerp(self, a: Vec3, b: Vec3, t: f64) -> Vec3 {
+        let mut out = [0.0; 3];
+        for i in 0..3 {
+            out[i] = a[i] + (b[i] - a[i]) * t;
+        }
+
+        if let Some((hue, powerless, method)) = self.hue() {
+            // Saturation or chroma is the middle component of every
+            // cylindrical space here. A grey's hue is powerless, so it takes
+            // the other colour's hue rather than swinging through unrelated
+            // ones.
+            let (mut from, mut to) = (a[hue], b[hue]);
+            if a[1] <= powerless {
+                from = to;
+            } else if b[1] <= powerless {
+                to = from;
+            }
+
+            let (from, to) = method.fix_up(from, to);
+            out[hue] = (from + (to - from) * t).rem_euclid(360.0);
+        }
+
+        out
+    }
+
+    /// Interpolates between two colours with opacities `alpha_a` and
+    /// `alpha_b`, premultiplying every component but hue by alpha as CSS
+    /// does. Returns the colour, with alpha divided back out, and its
+    /// opacity.
+    pub(crate) fn lerp_alpha(self, a: Vec3, alpha_a: f64, b: Vec3, alpha_b: f64, t: f64) -> (Vec3, f64) {
+        let alpha = alpha_a + (alpha_b - alpha_a) * t;
+        let mut out = self.lerp(a, b, t);
+        if alpha_a == alpha_b || alpha == 0.0 {
+            return (out, alpha);
+        }
+
+        let hue = self.hue().map(|(hue, _, _)| hue);
+        for i in (0..3).filter(|&i| Some(i) != hue) {
+            let (from, to) = (a[i] * alpha_a, b[i] * alpha_b);
+            out[i] = (from + (to - from) * t) / alpha;
+        }
+        (out, alpha)
+    }
+}
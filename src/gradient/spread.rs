@@ -0,0 +1,44 @@
+//! Handling positions outside a gradient.
+
+/// How a gradient is extended beyond its start and end when sampled at
+/// positions outside `0.0..=1.0`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Spread {
+    /// Clamp positions to the gradient, extending its end colours.
+    #[default]
+    Clamp,
+    /// Repeat the gradient, so `1.25` samples the same colour as `0.25`.
+    /// Each copy after the first runs up to and including its end, so every
+    /// whole number but `0.0` samples the end colour, as `1.0` does.
+    Repeat,
+    /// Repeat the gradient, reversing every other copy, so `1.25` samples
+    /// the same colour as `0.75`. Odd whole numbers sample the end colour
+    /// and even ones the start.
+    Mirror,
+}
+
+impl Spread {
+    /// Maps any position into `0.0..=1.0`. Infinities clamp whatever the
+    /// mode, and NaN maps to `0.0`.
+    pub(crate) fn apply(self, t: f64) -> f64 {
+        if !t.is_finite() {
+            return if t == f64::INFINITY { 1.0 } else { 0.0 };
+        }
+        match self {
+            Self::Clamp => t.clamp(0.0, 1.0),
+            Self::Repeat if (0.0..=1.0).contains(&t) => t,
+            Self::Repeat => match t.rem_euclid(1.0) {
+                0.0 => 1.0,
+                t => t,
+            },
+            Self::Mirror => {
+                let t = t.rem_euclid(2.0);
+                if t > 1.0 {
+                    2.0 - t
+                } else {
+                    t
+                }
+            }
+        }
+    }
+}
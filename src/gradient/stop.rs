@@ -0,0 +1,138 @@
+//! Colour stops.
+
+use crate::{
+    colour::{Component, Rgb, Rgba},
+    gradient::Easing,
+};
+
+/// A colour at a position along a gradient.
+///
+/// Positions are fractions of the gradient's length, so `0.0` is its start
+/// and `1.0` its end, but stops may lie outside that range as in CSS.
+///
+/// The transition from this stop to the next can be shaped in two ways. A
+/// hint is a CSS colour hint: the position, between the two stops, where
+/// the colours are mixed half and half. An easing then reshapes the
+/// transition further. A hint or easing on the last stop has no effect.
+///
+/// `alpha` is the stop's opacity, from `0.0` for transparent to `1.0` for
+/// opaque. Where it changes, colours are mixed with premultiplied alpha as
+/// in CSS, so fading to a transparent stop does not darken or tint the
+/// colours on the way.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ColourStop {
+    pub position: f64,
+    /// The colour, held at full precision whatever depth it was given at.
+    pub colour: Rgb<f64>,
+    pub alpha: f64,
+    pub easing: Easing,
+    pub hint: Option<f64>,
+}
+
+impl ColourStop {
+    pub fn new<T: Component>(position: f64, colour: Rgb<T>) -> Self {
+        Self {
+            position,
+            colour: colour.convert(),
+            alpha: 1.0,
+            easing: Easing::Linear,
+            hint: None,
+        }
+    }
+
+    /// Sets the stop's opacity, clamped to `0.0..=1.0`.
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// The stop's colour and opacity.
+    pub fn rgba(&self) -> Rgba {
+        Rgba::from_rgb(self.colour.convert(), (self.alpha.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    /// Sets the easing of the transition from this stop to the next.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the colour hint between this stop and the next, as a position
+    /// along the gradient like the stops' own.
+    pub fn with_hint(mut self, position: f64) -> Self {
+        self.hint = Some(position);
+        self
+    }
+
+    /// How far the colour has moved from this stop towards `next`, given
+    /// progress `t` in `0.0..=1.0` between their positions.
+    pub(crate) fn transition(&self, next: &ColourStop, t: f64) -> f64 {
+        let t = match self.hint {
+            Some(hint) => {
+                let hint = (hint - self.position) / (next.position - self.position);
+                apply_hint(hint, t)
+            }
+            None => t,
+        };
+        self.easing.apply(t)
+    }
+}
+
+/// The CSS transition hint curve: progress `t` is raised to the power that
+/// takes the hint's relative position `hint` to one half.
+fn apply_hint(hint: f64, t: f64) -> f64 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 || hint <= 0.0 {
+        1.0
+    } else if hint >= 1.0 {
+        0.0
+    } else {
+        t.powf(0.5f64.ln() / hint.ln())
+    }
+}
+
+impl<T: Component> From<(f64, Rgb<T>)> for ColourStop {
+    fn from((position, colour): (f64, Rgb<T>)) -> Self {
+        Self::new(position, colour)
+    }
+}
+
+impl From<(f64, Rgba)> for ColourStop {
+    fn from((position, colour): (f64, Rgba)) -> Self {
+        Self::new(position, colour.rgb()).with_alpha(colour.alpha() as f64 / 255.0)
+    }
+}
+
+/// Applies the CSS fix-up rule: a stop or hint positioned before an earlier
+/// one is moved up to the largest position before it. Two stops at the same
+/// position then make a hard edge between their colours.
+pub(crate) fn fix_up(stops: &mut [ColourStop]) {
+    let mut max = f64::NEG_INFINITY;
+    for stop in stops {
+        max = max.max(stop.position);
+        stop.position = max;
+        if let Some(hint) = &mut stop.hint {
+            max = max.max(*hint);
+            *hint = max;
+        }
+    }
+}
+
+/// Finds the pair of stops around `t`, and how far `t` lies between them.
+///
+/// When `t` lies before the first stop or at or after the last there is no
+/// pair, and the error holds the index of the stop whose colour applies
+/// instead. At a hard edge the later colour wins.
+pub(crate) fn segment(stops: &[ColourStop], t: f64) -> Result<(usize, f64), usize> {
+    let after = stops.partition_point(|stop| stop.position <= t);
+    if after == 0 {
+        return Err(0);
+    }
+    if after == stops.len() {
+        return Err(stops.len() - 1);
+    }
+
+    let (from, to) = (stops[after - 1].position, stops[after].position);
+    Ok((after - 1, (t - from) / (to - from)))
+}
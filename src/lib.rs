@@ -0,0 +1,11 @@
+//! Colour types, gradients and gradient rendering.
+//!
+//! The `colour` binary is a small front-end over this library; everything it
+//! does is available to other crates through the modules below.
+
+pub mod colour;
+pub mod gradient;
+pub mod render;
+
+pub use colour::{parse_css_colour, ColourParseError, Rgb, Rgba, RGB};
+pub use gradient::Gradient;
@@ -0,0 +1,292 @@
+//! Quantising high-precision colours down to 8 or 16 bits per channel.
+
+use super::RenderOptions;
+use crate::{
+    colour::{convert::Vec3, Component},
+    gradient,
+};
+
+use image::{ImageBuffer, Primitive, Rgb, Rgba};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use std::sync::OnceLock;
+
+/// How colours are rounded to 8 or 16 bits per channel when rasterising.
+///
+/// Long, gentle gradients show visible bands when every pixel is simply
+/// rounded. Dithering trades those bands for fine noise, which the eye
+/// averages back into the intended colour.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Dither {
+    /// Round each channel to the nearest value.
+    #[default]
+    None,
+    /// Ordered dithering with a 2×2 Bayer matrix.
+    Bayer2,
+    /// Ordered dithering with a 4×4 Bayer matrix.
+    Bayer4,
+    /// Ordered dithering with an 8×8 Bayer matrix.
+    Bayer8,
+    /// Ordered dithering with a tiled 64×64 blue-noise threshold map, which
+    /// avoids the cross-hatched look of the Bayer patterns.
+    BlueNoise,
+    /// Floyd–Steinberg error diffusion, scanning rows in alternating
+    /// directions.
+    FloydSteinberg,
+    /// Triangular-PDF noise of ±1 level added before rounding. The noise is
+    /// seeded, so the same image renders the same way each time.
+    Triangular,
+}
+
+/// Quantises an image of sRGB channels, nominally in `0.0..=1.0` and stored
+/// row by row, to channels of type `T`, at the options' size. Integer
+/// channels are mapped into the sRGB gamut and dithered as the options ask;
+/// float channels are stored as they are.
+pub(crate) fn quantise<T>(samples: &[Vec3], options: &RenderOptions) -> ImageBuffer<Rgb<T>, Vec<T>>
+where
+    T: Component + Primitive,
+{
+    ImageBuffer::from_raw(options.width, options.height, channels(samples, options))
+        .expect("one sample per pixel")
+}
+
+/// Quantises an image of sRGB channels with straight alpha, as
+/// [`quantise`] does, dithering alpha like the other channels.
+pub(crate) fn quantise_rgba<T>(samples: &[[f64; 4]], options: &RenderOptions) -> ImageBuffer<Rgba<T>, Vec<T>>
+where
+    T: Component + Primitive,
+{
+    ImageBuffer::from_raw(options.width, options.height, channels(samples, options))
+        .expect("one sample per pixel")
+}
+
+/// The channels of every pixel, row by row.
+fn channels<T: Component, const N: usize>(samples: &[[f64; N]], options: &RenderOptions) -> Vec<T> {
+    match T::LEVELS {
+        Some(max) => {
+            // The colour comes first in each sample, and any alpha after it.
+            let mapped: Vec<[f64; N]> = samples
+                .iter()
+                .map(|&sample| {
+                    let mut mapped = sample;
+                    let colour = gradient::to_gamut([sample[0], sample[1], sample[2]], options.gamut_mapping);
+                    mapped[..3].copy_from_slice(&colour);
+                    mapped
+                })
+                .collect();
+            levels(&mapped, options.width, options.height, options.dither, max)
+                .into_iter()
+                .map(|level| T::from_unit(level / max))
+                .collect()
+        }
+        None => samples.iter().flatten().map(|&c| T::from_unit(c)).collect(),
+    }
+}
+
+/// The channels of every pixel, row by row, rounded to whole levels out of
+/// `max`.
+fn levels<const N: usize>(samples: &[[f64; N]], width: u32, height: u32, dither: Dither, max: f64) -> Vec<f64> {
+    let scaled = |idx: usize| samples[idx].map(|c| c.clamp(0.0, 1.0) * max);
+    let to_pixel = |levels: [f64; N]| levels.map(|c| c.clamp(0.0, max));
+    let pixels = (0..height).flat_map(|y| (0..width).map(move |x| (x, y)));
+    let index = |x: u32, y: u32| y as usize * width as usize + x as usize;
+
+    match dither {
+        Dither::None => pixels
+            .flat_map(|(x, y)| to_pixel(scaled(index(x, y)).map(|c| (c + 0.5).floor())))
+            .collect(),
+        Dither::Bayer2 | Dither::Bayer4 | Dither::Bayer8 | Dither::BlueNoise => {
+            let (map, size) = match dither {
+                Dither::Bayer2 => (bayer(1), 2),
+                Dither::Bayer4 => (bayer(2), 4),
+                Dither::Bayer8 => (bayer(3), 8),
+                _ => (blue_noise().to_vec(), BLUE_NOISE_SIZE),
+            };
+            pixels
+                .flat_map(|(x, y)| {
+                    let threshold = map[(y as usize % size) * size + x as usize % size];
+                    to_pixel(scaled(index(x, y)).map(|c| (c + threshold).floor()))
+                })
+                .collect()
+        }
+        Dither::Triangular => {
+            let mut rng = StdRng::seed_from_u64(0x7d1f);
+            pixels
+                .flat_map(|(x, y)| {
+                    to_pixel(scaled(index(x, y)).map(|c| {
+                        let noise: f64 = rng.gen::<f64>() - rng.gen::<f64>();
+                        (c + noise + 0.5).floor()
+                    }))
+                })
+                .collect()
+        }
+        Dither::FloydSteinberg => floyd_steinberg(samples, width, height, max),
+    }
+}
+
+/// Builds a Bayer threshold matrix of side `2^order`, with thresholds
+/// evenly spread over `0.0..1.0`.
+fn bayer(order: u32) -> Vec<f64> {
+    let size = 1usize << order;
+    let mut map = vec![0.0; size * size];
+    for y in 0..size {
+        for x in 0..size {
+            // Interleave the bits of `x ^ y` and `y`, most significant first.
+            let (a, b) = (x ^ y, y);
+            let mut rank = 0;
+            for bit in (0..order).rev() {
+                rank = (rank << 2) | (((a >> bit) & 1) << 1) | ((b >> bit) & 1);
+            }
+            map[y * size + x] = (rank as f64 + 0.5) / (size * size) as f64;
+        }
+    }
+    map
+}
+
+const BLUE_NOISE_SIZE: usize = 64;
+
+/// A blue-noise threshold map, generated once with Ulichney's
+/// void-and-cluster method.
+fn blue_noise() -> &'static [f64] {
+    static MAP: OnceLock<Vec<f64>> = OnceLock::new();
+    MAP.get_or_init(|| void_and_cluster(BLUE_NOISE_SIZE, 1.5))
+}
+
+fn void_and_cluster(size: usize, sigma: f64) -> Vec<f64> {
+    let len = size * size;
+
+    // The Gaussian weight of every toroidal offset.
+    let kernel: Vec<f64> = (0..len)
+        .map(|idx| {
+            let wrap = |d: usize| d.min(size - d) as f64;
+            let (dx, dy) = (wrap(idx % size), wrap(idx / size));
+            (-(dx * dx + dy * dy) / (2.0 * sigma * sigma)).exp()
+        })
+        .collect();
+
+    /// A binary pattern with the energy each pixel receives from the set
+    /// pixels around it.
+    struct Pattern<'a> {
+        size: usize,
+        kernel: &'a [f64],
+        set: Vec<bool>,
+        energy: Vec<f64>,
+    }
+
+    impl Pattern<'_> {
+        fn toggle(&mut self, idx: usize) {
+            let sign = if self.set[idx] { -1.0 } else { 1.0 };
+            self.set[idx] = !self.set[idx];
+            let (x0, y0) = (idx % self.size, idx / self.size);
+            for (other, energy) in self.energy.iter_mut().enumerate() {
+                let dx = (other % self.size + self.size - x0) % self.size;
+                let dy = (other / self.size + self.size - y0) % self.size;
+                *energy += sign * self.kernel[dy * self.size + dx];
+            }
+        }
+
+        /// The set pixel with the most energy, or with `set` false, the unset
+        /// pixel with the least.
+        fn extreme(&self, set: bool) -> usize {
+            let candidates = (0..self.set.len()).filter(|&idx| self.set[idx] == set);
+            if set {
+                candidates
+                    .max_by(|&a, &b| self.energy[a].total_cmp(&self.energy[b]))
+                    .unwrap()
+            } else {
+                candidates
+                    .min_by(|&a, &b| self.energy[a].total_cmp(&self.energy[b]))
+                    .unwrap()
+            }
+        }
+    }
+
+    let mut pattern = Pattern {
+        size,
+        kernel: &kernel,
+        set: vec![false; len],
+        energy: vec![0.0; len],
+    };
+
+    // Start from a sparse random pattern and relax it by moving the pixel
+    // in the tightest cluster into the largest void until that changes
+    // nothing.
+    let mut rng = StdRng::seed_from_u64(0xb10e);
+    let initial = len / 10;
+    while pattern.set.iter().filter(|&&set| set).count() < initial {
+        let idx = rng.gen_range(0..len);
+        if !pattern.set[idx] {
+            pattern.toggle(idx);
+        }
+    }
+    loop {
+        let cluster = pattern.extreme(true);
+        pattern.toggle(cluster);
+        let void = pattern.extreme(false);
+        if void == cluster {
+            pattern.toggle(cluster);
+            break;
+        }
+        pattern.toggle(void);
+    }
+    let prototype = pattern.set.clone();
+    let energy = pattern.energy.clone();
+    let mut rank = vec![0usize; len];
+
+    // Rank the initial pixels by removing the tightest clusters first.
+    for r in (0..initial).rev() {
+        let cluster = pattern.extreme(true);
+        pattern.toggle(cluster);
+        rank[cluster] = r;
+    }
+
+    // Then fill the largest voids until the pattern is full. Past half full
+    // this is the same as finding the tightest cluster of unset pixels, as
+    // the method asks for.
+    pattern.set = prototype;
+    pattern.energy = energy;
+    for r in initial..len {
+        let void = pattern.extreme(false);
+        pattern.toggle(void);
+        rank[void] = r;
+    }
+
+    rank.iter()
+        .map(|&r| (r as f64 + 0.5) / len as f64)
+        .collect()
+}
+
+fn floyd_steinberg<const N: usize>(samples: &[[f64; N]], width: u32, height: u32, max: f64) -> Vec<f64> {
+    let (w, h) = (width as usize, height as usize);
+    let mut levels: Vec<[f64; N]> = samples
+        .iter()
+        .map(|c| c.map(|c| c.clamp(0.0, 1.0) * max))
+        .collect();
+    let mut out = vec![0.0; w * h * N];
+
+    for y in 0..h {
+        let reverse = y % 2 == 1;
+        for step in 0..w {
+            let x = if reverse { w - 1 - step } else { step };
+            let old = levels[y * w + x];
+            let new = old.map(|c| (c + 0.5).floor().clamp(0.0, max));
+            out[(y * w + x) * N..][..N].copy_from_slice(&new);
+
+            let forward = |x: usize| if reverse { x.checked_sub(1) } else { Some(x + 1).filter(|&x| x < w) };
+            let backward = |x: usize| if reverse { Some(x + 1).filter(|&x| x < w) } else { x.checked_sub(1) };
+            let mut spread = |x: Option<usize>, y: usize, weight: f64| {
+                if let (Some(x), true) = (x, y < h) {
+                    for c in 0..N {
+                        levels[y * w + x][c] += (old[c] - new[c]) * weight;
+                    }
+                }
+            };
+            spread(forward(x), y, 7.0 / 16.0);
+            spread(backward(x), y + 1, 3.0 / 16.0);
+            spread(Some(x), y + 1, 5.0 / 16.0);
+            spread(forward(x), y + 1, 1.0 / 16.0);
+        }
+    }
+
+    out
+}
@@ -0,0 +1,145 @@
+//! Rendering two-dimensional gradients.
+
+use super::{dither, save, RenderOptions};
+use crate::{
+    colour::{convert::Vec3, Component},
+    gradient::{BilinearGradient, MeshGradient, Point},
+};
+
+use image::{ImageBuffer, Primitive, Rgb};
+
+use std::error::Error;
+
+/// The fraction of the way along an axis of `len` pixels that pixel `i`
+/// sits, with the first and last pixels at `0.0` and `1.0`.
+fn unit(i: u32, len: u32) -> f64 {
+    if len > 1 {
+        i as f64 / (len - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+impl BilinearGradient {
+    /// Draws the gradient stretched over the whole image, with a corner
+    /// colour in each corner pixel. The options' shape is ignored.
+    pub fn render(&self, options: &RenderOptions) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
+        self.render_as(options)
+    }
+
+    /// Draws the gradient as [`BilinearGradient::render`] does, with
+    /// channels of any type.
+    pub fn render_as<T: Component + Primitive>(&self, options: &RenderOptions) -> ImageBuffer<Rgb<T>, Vec<T>> {
+        dither::quantise(&self.samples(options), options)
+    }
+
+    /// Draws the gradient and saves it in the options' pixel format, in the
+    /// file format given by the file's extension.
+    pub fn generate_image<S: AsRef<str>>(&self, filename: S, options: &RenderOptions) -> Result<(), Box<dyn Error>> {
+        let samples: Vec<_> = self.samples(options).into_iter().map(|colour| (colour, 1.0)).collect();
+        save(&samples, options, filename.as_ref())
+    }
+
+    /// Every pixel's colour, row by row.
+    fn samples(&self, options: &RenderOptions) -> Vec<Vec3> {
+        let (width, height) = (options.width, options.height);
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self.sample_unit(unit(x, width), unit(y, height)))
+            .collect()
+    }
+}
+
+impl MeshGradient {
+    /// Draws the mesh into a new image, with patch coordinates in pixels.
+    /// Pixels outside every patch are left black. The options' shape is
+    /// ignored.
+    pub fn render(&self, options: &RenderOptions) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
+        self.render_as(options)
+    }
+
+    /// Draws the mesh as [`MeshGradient::render`] does, with channels of any
+    /// type.
+    pub fn render_as<T: Component + Primitive>(&self, options: &RenderOptions) -> ImageBuffer<Rgb<T>, Vec<T>> {
+        dither::quantise(&self.samples(options), options)
+    }
+
+    /// Draws the mesh and saves it in the options' pixel format, in the file
+    /// format given by the file's extension.
+    pub fn generate_image<S: AsRef<str>>(&self, filename: S, options: &RenderOptions) -> Result<(), Box<dyn Error>> {
+        let samples: Vec<_> = self.samples(options).into_iter().map(|colour| (colour, 1.0)).collect();
+        save(&samples, options, filename.as_ref())
+    }
+
+    /// Every pixel's colour, row by row.
+    fn samples(&self, options: &RenderOptions) -> Vec<Vec3> {
+        let (width, height) = (options.width, options.height);
+        let mut samples = vec![[0.0; 3]; width as usize * height as usize];
+
+        for (index, patch) in self.patches().iter().enumerate() {
+            // Split the patch into small enough quads that each is close to
+            // flat, then fill each quad as two triangles.
+            let n = (patch.extent() / 4.0).ceil().clamp(1.0, 256.0) as usize;
+            let vertex = |i: usize, j: usize| {
+                let (u, v) = (i as f64 / n as f64, j as f64 / n as f64);
+                (patch.point(u, v), (u, v))
+            };
+            let mut fill = |a, b, c| {
+                triangle(a, b, c, width, height, |x, y, u, v| {
+                    samples[y as usize * width as usize + x as usize] = self.sample_unit(index, u, v);
+                })
+            };
+
+            for j in 0..n {
+                for i in 0..n {
+                    let (a, b) = (vertex(i, j), vertex(i + 1, j));
+                    let (c, d) = (vertex(i + 1, j + 1), vertex(i, j + 1));
+                    fill(a, b, c);
+                    fill(a, c, d);
+                }
+            }
+        }
+
+        samples
+    }
+}
+
+/// Coordinates `(u, v)` within a patch.
+type Uv = (f64, f64);
+
+/// Calls `plot` for every pixel whose centre lies inside the triangle, with
+/// the patch coordinates interpolated across it. Pixels on an edge shared
+/// by two triangles may be plotted by both.
+fn triangle<F>(a: (Point, Uv), b: (Point, Uv), c: (Point, Uv), width: u32, height: u32, mut plot: F)
+where
+    F: FnMut(u32, u32, f64, f64),
+{
+    let ((pa, ta), (pb, tb), (pc, tc)) = (a, b, c);
+    let area = (pb.0 - pa.0) * (pc.1 - pa.1) - (pc.0 - pa.0) * (pb.1 - pa.1);
+    if area.abs() < 1e-12 {
+        return;
+    }
+
+    // The pixels whose centres fall between `lo` and `hi`.
+    let range = |lo: f64, hi: f64, len: u32| {
+        let lo = (lo - 0.5).ceil().max(0.0) as i64;
+        let hi = (hi - 0.5).floor().min(len as f64 - 1.0) as i64;
+        lo..=hi
+    };
+    let xs = range(pa.0.min(pb.0).min(pc.0), pa.0.max(pb.0).max(pc.0), width);
+    let ys = range(pa.1.min(pb.1).min(pc.1), pa.1.max(pb.1).max(pc.1), height);
+
+    for y in ys {
+        for x in xs.clone() {
+            let (px, py) = (x as f64 + 0.5, y as f64 + 0.5);
+            let wa = ((pb.0 - px) * (pc.1 - py) - (pc.0 - px) * (pb.1 - py)) / area;
+            let wb = ((pc.0 - px) * (pa.1 - py) - (pa.0 - px) * (pc.1 - py)) / area;
+            let wc = 1.0 - wa - wb;
+            if wa >= -1e-9 && wb >= -1e-9 && wc >= -1e-9 {
+                let u = wa * ta.0 + wb * tb.0 + wc * tc.0;
+                let v = wa * ta.1 + wb * tb.1 + wc * tc.1;
+                plot(x as u32, y as u32, u.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! Rendering gradients to images.
+
+mod dither;
+mod mesh;
+
+pub use dither::Dither;
+
+use crate::{
+    colour::{convert::Vec3, Component, GamutMapping},
+    gradient::Gradient,
+};
+
+use image::{ImageBuffer, Primitive, Rgb, Rgba};
+
+use std::error::Error; 
+
+/// The direction a linear gradient runs across an image.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Orientation {
+    /// From the top edge to the bottom edge.
+    #[default]
+    Vertical,
+    /// From the left edge to the right edge.
+    Horizontal,
+    /// Along an angle in degrees, using the CSS `linear-gradient`
+    /// convention: `0.0` runs bottom to top, `90.0` left to right and
+    /// `180.0` top to bottom. The gradient line is long enough that the
+    /// start and end colours land exactly in opposite corners.
+    Angle(f64),
+}
+
+impl Orientation {
+    /// The sine and cosine of the CSS angle, exact for right angles so that
+    /// rounding error cannot leak into axis-aligned gradients.
+    fn sin_cos(self) -> (f64, f64) {
+        let degrees = match self {
+            Self::Vertical => 180.0,
+            Self::Horizontal => 90.0,
+            Self::Angle(degrees) => degrees,
+        };
+        match degrees.rem_euclid(360.0) {
+            0.0 => (0.0, 1.0),
+            90.0 => (1.0, 0.0),
+            180.0 => (0.0, -1.0),
+            270.0 => (-1.0, 0.0),
+            d => d.to_radians().sin_cos(),
+        }
+    }
+}
+
+/// The geometry of a gradient: how each pixel's position along it is found.
+///
+/// Points and radii are in pixels, measured from the top-left corner of the
+/// image, with pixel centres at half-pixel offsets as in CSS.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Shape {
+    /// A CSS `linear-gradient` in the given direction.
+    Linear(Orientation),
+    /// A CSS `radial-gradient` ellipse, with the horizontal and vertical
+    /// radii at which the gradient reaches its end. If either radius is
+    /// zero, negative or NaN the ellipse has no size, and every pixel lies
+    /// beyond its end.
+    Radial { centre: (f64, f64), radius: (f64, f64) },
+    /// A CSS `conic-gradient`, sweeping clockwise around the centre from
+    /// `from` degrees, where `0.0` points straight up.
+    Conic { centre: (f64, f64), from: f64 },
+    /// Concentric diamonds: like an ellipse, but measured with the taxicab
+    /// distance, reaching the end at the given horizontal and vertical
+    /// radii. Radii that are not positive are treated as for
+    /// [`Shape::Radial`].
+    Diamond { centre: (f64, f64), radius: (f64, f64) },
+}
+
+impl Shape {
+    /// A circular radial gradient.
+    pub fn circle(centre: (f64, f64), radius: f64) -> Self {
+        Self::Radial {
+            centre,
+            radius: (radius, radius),
+        }
+    }
+
+    /// An elliptical radial gradient.
+    pub fn ellipse(centre: (f64, f64), radius_x: f64, radius_y: f64) -> Self {
+        Self::Radial {
+            centre,
+            radius: (radius_x, radius_y),
+        }
+    }
+
+    /// A conic gradient starting at `from` degrees.
+    pub fn conic(centre: (f64, f64), from: f64) -> Self {
+        Self::Conic { centre, from }
+    }
+
+    /// A diamond gradient.
+    pub fn diamond(centre: (f64, f64), radius_x: f64, radius_y: f64) -> Self {
+        Self::Diamond {
+            centre,
+            radius: (radius_x, radius_y),
+        }
+    }
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self::Linear(Orientation::default())
+    }
+}
+
+/// The pixel format images are saved in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum PixelFormat {
+    /// 8-bit RGB, dropping any transparency.
+    #[default]
+    Rgb8,
+    /// 8-bit RGB with straight alpha.
+    Rgba8,
+    /// 16-bit RGB, dropping any transparency.
+    Rgb16,
+    /// 16-bit RGB with straight alpha.
+    Rgba16,
+}
+
+/// How to draw a gradient into an image.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RenderOptions {
+    pub width: u32,
+    pub height: u32,
+    pub shape: Shape,
+    pub dither: Dither,
+    pub format: PixelFormat,
+    /// How colours outside the sRGB gamut are brought inside before they
+    /// are quantised to integer channels. Float channels keep them.
+    pub gamut_mapping: GamutMapping,
+}
+
+impl RenderOptions {
+    /// A `width` by `height` image with a vertical linear gradient.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            shape: Shape::default(),
+            dither: Dither::default(),
+            format: PixelFormat::default(),
+            gamut_mapping: GamutMapping::default(),
+        }
+    }
+
+    pub fn with_shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    pub fn with_dither(mut self, dither: Dither) -> Self {
+        self.dither = dither;
+        self
+    }
+
+    pub fn with_format(mut self, format: PixelFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_gamut_mapping(mut self, gamut_mapping: GamutMapping) -> Self {
+        self.gamut_mapping = gamut_mapping;
+        self
+    }
+
+    /// Makes the gradient linear, running in the given direction.
+    pub fn with_orientation(self, orientation: Orientation) -> Self {
+        self.with_shape(Shape::Linear(orientation))
+    }
+
+    /// Builds the function mapping a pixel to its position along the
+    /// gradient.
+    fn position(&self) -> Box<dyn Fn(u32, u32) -> f64> {
+        let centre = |x: u32, y: u32| (x as f64 + 0.5, y as f64 + 0.5);
+
+        match self.shape {
+            Shape::Linear(orientation) => {
+                // Linear gradients measure from the first and last pixel
+                // centres, so that they land exactly on `0.0` and `1.0`, as
+                // CSS does for the edges of the box.
+                let (sin, cos) = orientation.sin_cos();
+                let width = self.width.saturating_sub(1) as f64;
+                let height = self.height.saturating_sub(1) as f64;
+                let length = (width * sin).abs() + (height * cos).abs();
+                if length == 0.0 {
+                    return Box::new(|_, _| 0.0);
+                }
+
+                Box::new(move |x, y| {
+                    let dx = x as f64 - width / 2.0;
+                    let dy = y as f64 - height / 2.0;
+                    (dx * sin - dy * cos) / length + 0.5
+                })
+            }
+            Shape::Radial {
+                radius: (rx, ry), ..
+            }
+            | Shape::Diamond {
+                radius: (rx, ry), ..
+            } if [rx, ry].iter().any(|r| *r <= 0.0 || r.is_nan()) => {
+                // A shape with no size ends before any pixel starts.
+                Box::new(|_, _| 1.0)
+            }
+            Shape::Radial {
+                centre: (cx, cy),
+                radius: (rx, ry),
+            } => Box::new(move |x, y| {
+                let (px, py) = centre(x, y);
+                ((px - cx) / rx).hypot((py - cy) / ry)
+            }),
+            Shape::Conic {
+                centre: (cx, cy),
+                from,
+            } => Box::new(move |x, y| {
+                let (px, py) = centre(x, y);
+                let angle = (px - cx).atan2(cy - py).to_degrees();
+                (angle - from).rem_euclid(360.0) / 360.0
+            }),
+            Shape::Diamond {
+                centre: (cx, cy),
+                radius: (rx, ry),
+            } => Box::new(move |x, y| {
+                let (px, py) = centre(x, y);
+                ((px - cx) / rx).abs() + ((py - cy) / ry).abs()
+            }),
+        }
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self::new(600, 600)
+    }
+}
+
+impl Gradient {
+    /// Draws the gradient into a new 8-bit image, resampling it to the
+    /// image's size. Colours are sampled at full precision and then dithered
+    /// down as the options ask. Transparency is dropped; see
+    /// [`Gradient::render_rgba`].
+    pub fn render(&self, options: &RenderOptions) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
+        self.render_as(options)
+    }
+
+    /// Draws the gradient as [`Gradient::render`] does, with channels of
+    /// any type, such as `u16` for a 16-bit image.
+    pub fn render_as<T: Component + Primitive>(&self, options: &RenderOptions) -> ImageBuffer<Rgb<T>, Vec<T>> {
+        let samples: Vec<_> = self.samples(options).map(|(colour, _)| colour).collect();
+        dither::quantise(&samples, options)
+    }
+
+    /// Draws the gradient as [`Gradient::render`] does, keeping each
+    /// pixel's opacity.
+    pub fn render_rgba(&self, options: &RenderOptions) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+        self.render_rgba_as(options)
+    }
+
+    /// Draws the gradient as [`Gradient::render_rgba`] does, with channels
+    /// of any type.
+    pub fn render_rgba_as<T: Component + Primitive>(&self, options: &RenderOptions) -> ImageBuffer<Rgba<T>, Vec<T>> {
+        let samples: Vec<_> = self
+            .samples(options)
+            .map(|([r, g, b], alpha)| [r, g, b, alpha])
+            .collect();
+        dither::quantise_rgba(&samples, options)
+    }
+
+    /// Draws the gradient and saves it in the options' pixel format, in the
+    /// file format given by the file's extension.
+    pub fn generate_image<S: AsRef<str>>(&self, filename: S, options: &RenderOptions) -> Result<(), Box<dyn Error>> {
+        let samples: Vec<_> = self.samples(options).collect();
+        save(&samples, options, filename.as_ref())
+    }
+
+    /// Every pixel's colour and opacity, row by row.
+    fn samples<'a>(&'a self, options: &RenderOptions) -> impl Iterator<Item = (Vec3, f64)> + 'a {
+        let position = options.position();
+        let width = options.width;
+        (0..options.height)
+            .flat_map(move |y| (0..width).map(move |x| (x, y)))
+            .map(move |(x, y)| self.sample_unit(position(x, y)))
+    }
+}
+
+/// Quantises an image of colours and opacities, stored row by row, and
+/// saves it in the options' pixel format.
+fn save(samples: &[(Vec3, f64)], options: &RenderOptions, filename: &str) -> Result<(), Box<dyn Error>> {
+    let opaque = || -> Vec<Vec3> { samples.iter().map(|&(colour, _)| colour).collect() };
+    let straight = || -> Vec<[f64; 4]> {
+        samples
+            .iter()
+            .map(|&([r, g, b], alpha)| [r, g, b, alpha])
+            .collect()
+    };
+    match options.format {
+        PixelFormat::Rgb8 => dither::quantise::<u8>(&opaque(), options).save(filename)?,
+        PixelFormat::Rgba8 => dither::quantise_rgba::<u8>(&straight(), options).save(filename)?,
+        PixelFormat::Rgb16 => dither::quantise::<u16>(&opaque(), options).save(filename)?,
+        PixelFormat::Rgba16 => dither::quantise_rgba::<u16>(&straight(), options).save(filename)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use colour::{
+    colour::PremultipliedRgba,
+    gradient::{ColourStop, InterpolationSpace},
+    render::{Orientation, PixelFormat, RenderOptions},
+    Gradient, Rgba, RGB,
+};
+
+use std::{fs, path::PathBuf};
+
+mod common;
+
+/// Opaque red fading to transparent blue.
+fn red_to_clear_blue(space: InterpolationSpace) -> Gradient {
+    let stops = vec![
+        ColourStop::new(0.0, RGB::new(255, 0, 0)),
+        ColourStop::new(1.0, RGB::new(0, 0, 255)).with_alpha(0.0),
+    ];
+    Gradient::from_stops(stops, 2, space).unwrap()
+}
+
+#[test]
+fn fading_out_keeps_the_opaque_colour() {
+    // Premultiplied, transparent blue adds nothing, so the colour stays red
+    // all the way along rather than passing through a dim purple.
+    for space in [
+        InterpolationSpace::Srgb,
+        InterpolationSpace::LinearSrgb,
+        InterpolationSpace::Lab,
+        InterpolationSpace::Oklab,
+    ] {
+        let gradient = red_to_clear_blue(space);
+        assert_eq!(gradient.sample_rgba(0.0), Rgba::new(255, 0, 0, 255));
+        assert_eq!(
+            gradient.sample_rgba(0.25),
+            Rgba::new(255, 0, 0, 191),
+            "{:?}",
+            space
+        );
+        assert_eq!(
+            gradient.sample_rgba(0.5),
+            Rgba::new(255, 0, 0, 128),
+            "{:?}",
+            space
+        );
+        assert_eq!(
+            gradient.sample_rgba(0.75),
+            Rgba::new(255, 0, 0, 64),
+            "{:?}",
+            space
+        );
+        assert_eq!(gradient.sample_rgba(1.0), Rgba::new(0, 0, 255, 0));
+    }
+}
+
+#[test]
+fn partial_opacity_weights_the_colours() {
+    // Halfway from opaque red to half-transparent blue, alpha is 0.75 and
+    // red carries twice blue's weight: (1.0, 0.0, 0.5 × 0.5) / 2 / 0.75.
+    let stops = vec![
+        ColourStop::new(0.0, RGB::new(255, 0, 0)),
+        ColourStop::new(1.0, RGB::new(0, 0, 255)).with_alpha(0.5),
+    ];
+    let gradient = Gradient::from_stops(stops, 2, InterpolationSpace::Srgb).unwrap();
+    assert_eq!(gradient.sample_rgba(0.5), Rgba::new(170, 0, 85, 191));
+}
+
+#[test]
+fn equal_opacities_mix_like_opaque_colours() {
+    for (start, end) in common::pairs(100) {
+        let stops = vec![
+            ColourStop::new(0.0, start).with_alpha(0.4),
+            ColourStop::new(1.0, end).with_alpha(0.4),
+        ];
+        let translucent = Gradient::from_stops(stops, 2, InterpolationSpace::Oklab).unwrap();
+        let opaque = Gradient::new(start, end, 2, InterpolationSpace::Oklab);
+        for &t in &[0.0, 0.3, 0.5, 1.0] {
+            assert_eq!(
+                translucent.sample_rgba(t),
+                Rgba::from_rgb(opaque.sample(t), 102)
+            );
+        }
+    }
+}
+
+#[test]
+fn premultiplied_round_trips() {
+    // Every premultiplied colour survives being divided out and multiplied
+    // back in.
+    for a in 0..=255u8 {
+        for c in 0..=a {
+            let premultiplied = PremultipliedRgba::new(c, a - c, c / 2, a);
+            let straight = premultiplied.unpremultiply();
+            assert_eq!(straight.premultiply(), premultiplied, "via {:?}", straight);
+        }
+    }
+
+    // Opaque colours are unchanged either way.
+    for rgb in common::colours(1_000) {
+        let rgba = Rgba::from_rgb(rgb, 255);
+        assert_eq!(rgba.premultiply().unpremultiply(), rgba);
+    }
+}
+
+#[test]
+fn premultiplying_scales_by_alpha() {
+    let premultiplied = Rgba::new(255, 128, 0, 128).premultiply();
+    assert_eq!(premultiplied.to_tuple(), (128, 64, 0, 128));
+    assert_eq!(premultiplied.unpremultiply(), Rgba::new(255, 128, 0, 128));
+    assert_eq!(
+        Rgba::new(10, 20, 30, 0).premultiply().unpremultiply(),
+        Rgba::TRANSPARENT
+    );
+    // Channels above alpha cannot be premultiplied; they are lowered to it.
+    assert_eq!(
+        PremultipliedRgba::new(200, 50, 0, 100).to_tuple(),
+        (100, 50, 0, 100)
+    );
+}
+
+fn options() -> RenderOptions {
+    RenderOptions::new(2, 3).with_orientation(Orientation::Vertical)
+}
+
+#[test]
+fn render_rgba_keeps_opacity() {
+    let gradient = red_to_clear_blue(InterpolationSpace::Oklab);
+
+    let image = gradient.render_rgba(&options());
+    for x in 0..2 {
+        assert_eq!(image.get_pixel(x, 0).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(x, 1).0, [255, 0, 0, 128]);
+        assert_eq!(image.get_pixel(x, 2).0, [0, 0, 255, 0]);
+    }
+
+    let image = gradient.render_rgba_as::<u16>(&options());
+    assert_eq!(image.get_pixel(0, 0).0, [65535, 0, 0, 65535]);
+    assert_eq!(image.get_pixel(0, 1).0, [65535, 0, 0, 32768]);
+    assert_eq!(image.get_pixel(0, 2).0, [0, 0, 65535, 0]);
+
+    let image = gradient.render_rgba_as::<f64>(&options());
+    assert_eq!(image.get_pixel(0, 1).0[3], 0.5);
+
+    // The opaque renderers drop alpha.
+    let image = gradient.render(&options());
+    assert_eq!(image.get_pixel(0, 1).0, [255, 0, 0]);
+}
+
+/// A path in the temporary directory that is removed when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> Self {
+        let name = format!("colour-{}-{}", std::process::id(), name);
+        Self(std::env::temp_dir().join(name))
+    }
+
+    fn path(&self) -> &str {
+        self.0.to_str().unwrap()
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn pixel_formats_are_saved_as_asked() {
+    let gradient = red_to_clear_blue(InterpolationSpace::Oklab);
+
+    let file = TempFile::new("rgb8.png");
+    gradient.generate_image(file.path(), &options()).unwrap();
+    let image = image::open(file.path()).unwrap().into_rgb8();
+    assert_eq!(image.get_pixel(1, 1).0, [255, 0, 0]);
+
+    let file = TempFile::new("rgba8.png");
+    let rgba8 = options().with_format(PixelFormat::Rgba8);
+    gradient.generate_image(file.path(), &rgba8).unwrap();
+    let image = image::open(file.path()).unwrap();
+    assert_eq!(image.color(), image::ColorType::Rgba8);
+    assert_eq!(image.into_rgba8(), gradient.render_rgba(&rgba8));
+
+    let file = TempFile::new("rgb16.png");
+    let rgb16 = options().with_format(PixelFormat::Rgb16);
+    gradient.generate_image(file.path(), &rgb16).unwrap();
+    let image = image::open(file.path()).unwrap();
+    assert_eq!(image.color(), image::ColorType::Rgb16);
+    assert_eq!(image.into_rgb16(), gradient.render_as::<u16>(&rgb16));
+
+    let file = TempFile::new("rgba16.png");
+    let rgba16 = options().with_format(PixelFormat::Rgba16);
+    gradient.generate_image(file.path(), &rgba16).unwrap();
+    let image = image::open(file.path()).unwrap();
+    assert_eq!(image.color(), image::ColorType::Rgba16);
+    let image = image.into_rgba16();
+    assert_eq!(image, gradient.render_rgba_as::<u16>(&rgba16));
+    assert_eq!(image.get_pixel(0, 1).0, [65535, 0, 0, 32768]);
+}
@@ -0,0 +1,90 @@
+use colour::RGB;
+
+const BLACK: RGB = RGB::new(0, 0, 0);
+const WHITE: RGB = RGB::new(255, 255, 255);
+
+#[test]
+fn addition_at_the_top_of_the_range() {
+    let (a, b) = (RGB::new(200, 100, 0), RGB::new(100, 100, 255));
+    assert_eq!(a.saturating_add(b), RGB::new(255, 200, 255));
+    assert_eq!(a + b, a.saturating_add(b));
+    assert_eq!(a.checked_add(b), None);
+    assert_eq!(a.wrapping_add(b), RGB::new(44, 200, 255));
+
+    // Exactly 255 is not an overflow.
+    let c = RGB::new(55, 155, 155);
+    assert_eq!(a.checked_add(c), Some(RGB::new(255, 255, 155)));
+    assert_eq!(a.wrapping_add(c), RGB::new(255, 255, 155));
+    assert_eq!(WHITE.checked_add(RGB::new(0, 0, 1)), None);
+    assert_eq!(WHITE.wrapping_add(RGB::new(1, 1, 1)), BLACK);
+}
+
+#[test]
+fn subtraction_at_the_bottom_of_the_range() {
+    let (a, b) = (RGB::new(10, 100, 255), RGB::new(20, 100, 5));
+    assert_eq!(a.saturating_sub(b), RGB::new(0, 0, 250));
+    assert_eq!(a - b, a.saturating_sub(b));
+    assert_eq!(a.checked_sub(b), None);
+    assert_eq!(a.wrapping_sub(b), RGB::new(246, 0, 250));
+
+    // Exactly 0 is not an underflow.
+    let c = RGB::new(10, 0, 255);
+    assert_eq!(a.checked_sub(c), Some(RGB::new(0, 100, 0)));
+    assert_eq!(BLACK.checked_sub(RGB::new(0, 1, 0)), None);
+    assert_eq!(BLACK.wrapping_sub(RGB::new(1, 1, 1)), WHITE);
+    assert_eq!(BLACK - WHITE, BLACK);
+}
+
+#[test]
+fn multiplying_colours_is_the_multiply_blend() {
+    let c = RGB::new(12, 128, 250);
+    assert_eq!(c * WHITE, c);
+    assert_eq!(WHITE * c, c);
+    assert_eq!(c * BLACK, BLACK);
+    // 128 × 128 / 255 is 64.25.
+    assert_eq!(
+        RGB::new(128, 128, 128) * RGB::new(128, 128, 128),
+        RGB::new(64, 64, 64)
+    );
+    // The product's nearest value either side of a half: 127 / 255 rounds
+    // down and 128 / 255 rounds up.
+    assert_eq!(
+        RGB::new(1, 127, 1) * RGB::new(127, 1, 128),
+        RGB::new(0, 0, 1)
+    );
+    // 200 × 100 / 255 is 78.43; 255 × 254 / 255 is exactly 254.
+    assert_eq!(
+        RGB::new(200, 255, 0) * RGB::new(100, 254, 0),
+        RGB::new(78, 254, 0)
+    );
+}
+
+#[test]
+fn scaling_rounds_and_saturates() {
+    // Halves round away from zero.
+    assert_eq!(RGB::new(1, 3, 5) * 0.5, RGB::new(1, 2, 3));
+    assert_eq!(RGB::new(100, 200, 7) * 0.3, RGB::new(30, 60, 2));
+    assert_eq!(RGB::new(100, 200, 0) * 2.0, RGB::new(200, 255, 0));
+    assert_eq!(RGB::new(100, 200, 0) * -1.0, BLACK);
+    assert_eq!(RGB::new(100, 200, 0) * 0.0, BLACK);
+    assert_eq!(RGB::new(100, 200, 0) * f64::INFINITY, RGB::new(255, 255, 0));
+    assert_eq!(RGB::new(100, 200, 255) * f64::NAN, BLACK);
+    assert_eq!(WHITE * 1.0, WHITE);
+}
+
+#[test]
+fn division_rounds_and_saturates() {
+    assert_eq!(RGB::new(3, 5, 255) / 2.0, RGB::new(2, 3, 128));
+    assert_eq!(RGB::new(100, 200, 30) / 0.5, RGB::new(200, 255, 60));
+    assert_eq!(RGB::new(100, 200, 30) / -2.0, BLACK);
+    assert_eq!(RGB::new(100, 200, 30) / f64::INFINITY, BLACK);
+    assert_eq!(RGB::new(100, 200, 30) / f64::NAN, BLACK);
+}
+
+#[test]
+fn division_by_zero() {
+    assert_eq!(RGB::new(0, 1, 255) / 0.0, RGB::new(0, 255, 255));
+    assert_eq!(BLACK / 0.0, BLACK);
+    // Negative zero sends non-zero channels to negative infinity instead.
+    assert_eq!(RGB::new(0, 1, 255) / -0.0, BLACK);
+}
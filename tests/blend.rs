@@ -0,0 +1,78 @@
+use colour::{colour::BlendError, RGB};
+
+mod common;
+
+#[test]
+fn blend_with_one_weight_gives_that_colour() {
+    for (a, b) in common::pairs(10_000) {
+        assert_eq!(a.blend(b, 1.0, 0.0), Ok(a));
+        assert_eq!(a.blend(b, 0.0, 1.0), Ok(b));
+        assert_eq!(a.blend_linear(b, 1.0, 0.0), Ok(a));
+        assert_eq!(a.blend_linear(b, 0.0, 1.0), Ok(b));
+    }
+}
+
+#[test]
+fn blend_normalises_weights_within_tolerance() {
+    let (a, b) = (RGB::new(255, 0, 0), RGB::new(0, 0, 255));
+    // 0.6 + 0.3 rounds below 0.9, so the weights add up to just under 1.
+    let alpha = 0.6 + 0.3;
+    assert_ne!(alpha + 0.1, 1.0);
+    assert_eq!(a.blend(b, alpha, 0.1), a.blend(b, 0.9, 0.1));
+    assert!(a.blend(b, 0.5, 0.5 + 1e-9).is_ok());
+}
+
+#[test]
+fn blend_rejects_invalid_weights() {
+    let (a, b) = (RGB::new(255, 0, 0), RGB::new(0, 0, 255));
+    for &(alpha, beta) in &[
+        (0.5, 0.6),
+        (0.2, 0.2),
+        (1.5, -0.5),
+        (f64::NAN, 1.0),
+        (f64::INFINITY, 0.0),
+    ] {
+        assert!(
+            matches!(a.blend(b, alpha, beta), Err(BlendError::InvalidWeights { .. })),
+            "{} + {}",
+            alpha,
+            beta
+        );
+        assert!(a.blend_linear(b, alpha, beta).is_err());
+    }
+}
+
+#[test]
+fn blend_does_not_overflow() {
+    let white = RGB::new(255, 255, 255);
+    for &t in &[0.25, 0.5, 0.75] {
+        assert_eq!(white.blend(white, 1.0 - t, t), Ok(white));
+        assert_eq!(white.blend_linear(white, 1.0 - t, t), Ok(white));
+    }
+}
+
+#[test]
+fn mix_hits_both_endpoints() {
+    for (a, b) in common::pairs(10_000) {
+        assert_eq!(a.mix(b, 0.0), a);
+        assert_eq!(a.mix(b, 1.0), b);
+        assert_eq!(a.mix_linear(b, 0.0), a);
+        assert_eq!(a.mix_linear(b, 1.0), b);
+    }
+}
+
+#[test]
+fn mix_clamps_t() {
+    let (a, b) = (RGB::new(10, 20, 30), RGB::new(200, 100, 0));
+    assert_eq!(a.mix(b, -1.0), a);
+    assert_eq!(a.mix(b, 2.0), b);
+    assert_eq!(a.mix(b, f64::NAN), a);
+}
+
+#[test]
+fn mix_halfway() {
+    let (black, white) = (RGB::new(0, 0, 0), RGB::new(255, 255, 255));
+    assert_eq!(black.mix(white, 0.5), RGB::new(128, 128, 128));
+    // Half the light of white is #bcbcbc in sRGB.
+    assert_eq!(black.mix_linear(white, 0.5), RGB::new(188, 188, 188));
+}
@@ -0,0 +1,48 @@
+//! Test colours shared by the integration tests.
+
+// Each test crate compiles its own copy of this module and uses only part of it.
+#![allow(dead_code)]
+
+use colour::RGB;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0x5eed)
+}
+
+/// Every grey, the primaries and secondaries, their neighbours at black and
+/// white, and `random` colours drawn with a fixed seed.
+pub fn colours(random: usize) -> Vec<RGB> {
+    let mut colours: Vec<RGB> = (0..=255).map(|v| RGB::new(v, v, v)).collect();
+    for &(r, g, b) in &[
+        (255, 0, 0),
+        (0, 255, 0),
+        (0, 0, 255),
+        (255, 255, 0),
+        (0, 255, 255),
+        (255, 0, 255),
+        (1, 0, 0),
+        (254, 255, 255),
+    ] {
+        colours.push(RGB::new(r, g, b));
+    }
+
+    let mut rng = rng();
+    colours.extend((0..random).map(|_| rng.gen::<RGB>()));
+    colours
+}
+
+/// Black and white, two primaries, grey and a primary, a colour with
+/// itself, and `random` pairs drawn with a fixed seed.
+pub fn pairs(random: usize) -> Vec<(RGB, RGB)> {
+    let mut pairs = vec![
+        (RGB::new(0, 0, 0), RGB::new(255, 255, 255)),
+        (RGB::new(255, 0, 0), RGB::new(0, 0, 255)),
+        (RGB::new(128, 128, 128), RGB::new(0, 255, 0)),
+        (RGB::new(255, 255, 0), RGB::new(255, 255, 0)),
+    ];
+    let mut rng = rng();
+    pairs.extend((0..random).map(|_| (rng.gen(), rng.gen())));
+    pairs
+}
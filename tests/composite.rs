@@ -0,0 +1,165 @@
+use colour::{
+    colour::{BlendMode, PorterDuff},
+    Rgba, RGB,
+};
+
+#[test]
+fn porter_duff_operators_match_the_specification() {
+    // Red at 60% over blue at 40%; the expected values come from the
+    // specification's Fa and Fb table.
+    let (source, backdrop) = (Rgba::new(255, 0, 0, 153), Rgba::new(0, 0, 255, 102));
+    let cases = [
+        (PorterDuff::Clear, Rgba::TRANSPARENT),
+        (PorterDuff::Copy, Rgba::new(255, 0, 0, 153)),
+        (PorterDuff::Destination, Rgba::new(0, 0, 255, 102)),
+        (PorterDuff::SourceOver, Rgba::new(201, 0, 54, 194)),
+        (PorterDuff::DestinationOver, Rgba::new(121, 0, 134, 194)),
+        (PorterDuff::SourceIn, Rgba::new(255, 0, 0, 61)),
+        (PorterDuff::DestinationIn, Rgba::new(0, 0, 255, 61)),
+        (PorterDuff::SourceOut, Rgba::new(255, 0, 0, 92)),
+        (PorterDuff::DestinationOut, Rgba::new(0, 0, 255, 41)),
+        (PorterDuff::SourceAtop, Rgba::new(153, 0, 102, 102)),
+        (PorterDuff::DestinationAtop, Rgba::new(153, 0, 102, 153)),
+        (PorterDuff::Xor, Rgba::new(177, 0, 78, 133)),
+    ];
+    for (operator, expected) in cases {
+        assert_eq!(
+            source.composite(backdrop, operator),
+            expected,
+            "{:?}",
+            operator
+        );
+    }
+    assert_eq!(PorterDuff::default(), PorterDuff::SourceOver);
+}
+
+#[test]
+fn shorthands_match_their_operators() {
+    let (source, backdrop) = (Rgba::new(10, 200, 30, 170), Rgba::new(240, 20, 90, 60));
+    let cases = [
+        (source.over(backdrop), PorterDuff::SourceOver),
+        (source.inside(backdrop), PorterDuff::SourceIn),
+        (source.outside(backdrop), PorterDuff::SourceOut),
+        (source.atop(backdrop), PorterDuff::SourceAtop),
+        (source.xor(backdrop), PorterDuff::Xor),
+    ];
+    for (actual, operator) in cases {
+        assert_eq!(
+            actual,
+            source.composite(backdrop, operator),
+            "{:?}",
+            operator
+        );
+    }
+}
+
+#[test]
+fn fully_transparent_results_are_transparent_black() {
+    let clear = Rgba::new(255, 255, 255, 0);
+    assert_eq!(clear.over(clear), Rgba::TRANSPARENT);
+    assert_eq!(
+        Rgba::new(255, 0, 0, 255).outside(Rgba::new(0, 0, 255, 255)),
+        Rgba::TRANSPARENT
+    );
+}
+
+#[test]
+fn blend_modes_match_the_specification() {
+    // The backdrop is (0.2, 0.6, 0.8) and the source (0.4, 0.8, 0.6); the
+    // expected values come from the specification's formulas.
+    let (backdrop, source) = (RGB::new(51, 153, 204), RGB::new(102, 204, 153));
+    let cases = [
+        (BlendMode::Normal, RGB::new(102, 204, 153)),
+        (BlendMode::Multiply, RGB::new(20, 122, 122)),
+        (BlendMode::Screen, RGB::new(133, 235, 235)),
+        (BlendMode::Overlay, RGB::new(41, 214, 214)),
+        (BlendMode::Darken, RGB::new(51, 153, 153)),
+        (BlendMode::Lighten, RGB::new(102, 204, 204)),
+        (BlendMode::ColourDodge, RGB::new(85, 255, 255)),
+        (BlendMode::ColourBurn, RGB::new(0, 128, 170)),
+        (BlendMode::HardLight, RGB::new(41, 214, 214)),
+        (BlendMode::SoftLight, RGB::new(43, 180, 209)),
+        (BlendMode::Difference, RGB::new(51, 51, 51)),
+        (BlendMode::Exclusion, RGB::new(112, 112, 112)),
+        (BlendMode::Hue, RGB::new(29, 182, 106)),
+        (BlendMode::Saturation, RGB::new(77, 145, 179)),
+        (BlendMode::Colour, RGB::new(62, 164, 113)),
+        (BlendMode::Luminosity, RGB::new(91, 193, 244)),
+    ];
+    for (mode, expected) in cases {
+        assert_eq!(source.blend_with(backdrop, mode), expected, "{:?}", mode);
+    }
+    assert_eq!(BlendMode::default(), BlendMode::Normal);
+}
+
+#[test]
+fn dodge_and_burn_edge_cases() {
+    let (black, white) = (RGB::new(0, 0, 0), RGB::new(255, 255, 255));
+    let grey = RGB::new(128, 128, 128);
+    // A black backdrop stays black under dodge, even under a white source.
+    assert_eq!(white.blend_with(black, BlendMode::ColourDodge), black);
+    assert_eq!(white.blend_with(grey, BlendMode::ColourDodge), white);
+    // A white backdrop stays white under burn, even under a black source.
+    assert_eq!(black.blend_with(white, BlendMode::ColourBurn), white);
+    assert_eq!(black.blend_with(grey, BlendMode::ColourBurn), black);
+}
+
+#[test]
+fn non_separable_modes_clip_into_range() {
+    let blue = RGB::new(0, 0, 255);
+    // Mid-grey's luminosity lifts blue's channels past 1.0, so the colour is
+    // pulled back towards grey with its luminosity kept.
+    assert_eq!(
+        RGB::new(128, 128, 128).blend_with(blue, BlendMode::Luminosity),
+        RGB::new(112, 112, 255)
+    );
+    // A dark luminosity pushes cyan's red channel below zero.
+    assert_eq!(
+        RGB::new(20, 20, 20).blend_with(RGB::new(0, 255, 255), BlendMode::Luminosity),
+        RGB::new(0, 29, 29)
+    );
+    // Colour keeps the backdrop's luminosity: white's is 1.0, which only
+    // white has.
+    let white = RGB::new(255, 255, 255);
+    assert_eq!(
+        RGB::new(255, 0, 0).blend_with(white, BlendMode::Colour),
+        white
+    );
+    // A grey source has no saturation to give.
+    let grey = RGB::new(90, 90, 90);
+    let desaturated = grey.blend_with(RGB::new(200, 40, 60), BlendMode::Saturation);
+    let (r, g, b) = desaturated.to_tuple();
+    assert!(r == g && g == b, "{:?}", desaturated);
+}
+
+#[test]
+fn rgba_blend_over_a_transparent_backdrop_is_the_source() {
+    let source = Rgba::new(102, 204, 153, 200);
+    for mode in [BlendMode::Multiply, BlendMode::Difference, BlendMode::Hue] {
+        assert_eq!(
+            source.blend_with(Rgba::TRANSPARENT, mode),
+            source,
+            "{:?}",
+            mode
+        );
+    }
+}
+
+#[test]
+fn rgba_blend_mixes_by_backdrop_alpha() {
+    let (source, backdrop) = (RGB::new(102, 204, 153), RGB::new(51, 153, 204));
+
+    // Opaque colours blend as `RGB` does.
+    let opaque =
+        Rgba::from_rgb(source, 255).blend_with(Rgba::from_rgb(backdrop, 255), BlendMode::Multiply);
+    assert_eq!(
+        opaque,
+        Rgba::from_rgb(source.blend_with(backdrop, BlendMode::Multiply), 255)
+    );
+
+    // At 40% the backdrop takes 40% of the blended colour and leaves the
+    // source unblended for the rest.
+    let partial =
+        Rgba::from_rgb(source, 255).blend_with(Rgba::from_rgb(backdrop, 102), BlendMode::Multiply);
+    assert_eq!(partial, Rgba::new(69, 171, 141, 255));
+}
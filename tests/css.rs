@@ -0,0 +1,252 @@
+use colour::{colour::CssColour, parse_css_colour, ColourParseError};
+
+fn parse(css: &str) -> [f64; 4] {
+    let colour = parse_css_colour(css).unwrap_or_else(|e| panic!("{:?}: {}", css, e));
+    [colour.red, colour.green, colour.blue, colour.alpha]
+}
+
+fn assert_colour(css: &str, expected: [f64; 4]) {
+    let actual = parse(css);
+    for (a, e) in actual.iter().zip(&expected) {
+        assert!(
+            (a - e).abs() < 1e-4,
+            "{:?}: {:?} != {:?}",
+            css,
+            actual,
+            expected
+        );
+    }
+}
+
+fn assert_same(a: &str, b: &str) {
+    assert_colour(a, parse(b));
+}
+
+fn unexpected(expected: &'static str, span: std::ops::Range<usize>) -> ColourParseError {
+    ColourParseError::UnexpectedToken { expected, span }
+}
+
+#[test]
+fn hex_names_and_transparent() {
+    assert_colour("#f80", [1.0, 0x88 as f64 / 255.0, 0.0, 1.0]);
+    assert_colour(
+        "#ff880080",
+        [1.0, 0x88 as f64 / 255.0, 0.0, 0x80 as f64 / 255.0],
+    );
+    assert_colour("RebeccaPurple", [0.4, 0.2, 0.6, 1.0]);
+    assert_colour("  transparent ", [0.0; 4]);
+}
+
+#[test]
+fn rgb_modern_and_legacy() {
+    let orange = [1.0, 136.0 / 255.0, 0.0, 1.0];
+    assert_colour("rgb(255 136 0)", orange);
+    assert_colour("rgb(255, 136, 0)", orange);
+    assert_colour("RGB(255,136,0)", orange);
+    assert_colour("rgb(100% 50% 0%)", [1.0, 0.5, 0.0, 1.0]);
+    assert_colour("rgb(100% 136 0)", orange);
+    assert_colour("rgb(300 -20 0)", [1.0, 0.0, 0.0, 1.0]);
+
+    assert_colour("rgb(255 136 0 / 50%)", [1.0, 136.0 / 255.0, 0.0, 0.5]);
+    assert_colour("rgba(255, 136, 0, 0.5)", [1.0, 136.0 / 255.0, 0.0, 0.5]);
+    assert_colour("rgb(255 136 0 / 2)", orange);
+}
+
+#[test]
+fn none_is_zero_in_the_modern_syntax_only() {
+    assert_colour("rgb(none 136 0 / none)", [0.0, 136.0 / 255.0, 0.0, 0.0]);
+    assert_eq!(
+        parse_css_colour("rgb(none, 0, 0)"),
+        Err(unexpected("a number or percentage", 4..8))
+    );
+}
+
+#[test]
+fn legacy_syntax_rules() {
+    assert_eq!(
+        parse_css_colour("rgb(255, 0,, 0)"),
+        Err(unexpected("a colour component", 11..12))
+    );
+    assert_eq!(
+        parse_css_colour("rgb(255, 0 0)"),
+        Err(unexpected("','", 11..12))
+    );
+    assert_eq!(
+        parse_css_colour("rgb(255 0, 0)"),
+        Err(unexpected("a colour component", 9..10))
+    );
+    assert_eq!(
+        parse_css_colour("rgb(255, 0, 0 / 1)"),
+        Err(unexpected("',' or ')'", 14..15))
+    );
+    assert_eq!(
+        parse_css_colour("rgb(255 0 0, 1)"),
+        Err(unexpected("'/' or ')'", 11..12))
+    );
+    assert_eq!(
+        parse_css_colour("rgb(255, 0, 0,)"),
+        Err(unexpected("an alpha value", 14..15))
+    );
+    assert_eq!(
+        parse_css_colour("rgb(100%, 136, 0)"),
+        Err(unexpected("a percentage", 10..13))
+    );
+    assert_eq!(
+        parse_css_colour("hsl(120, 100, 50%)"),
+        Err(unexpected("a percentage", 9..12))
+    );
+    assert_eq!(
+        parse_css_colour("hwb(0, 0%, 0%)"),
+        Err(unexpected("a colour component", 5..6))
+    );
+}
+
+#[test]
+fn hsl_and_hwb() {
+    assert_colour("hsl(120 100% 50%)", [0.0, 1.0, 0.0, 1.0]);
+    assert_colour("hsl(120, 100%, 50%)", [0.0, 1.0, 0.0, 1.0]);
+    assert_colour("hsl(120 100 50)", [0.0, 1.0, 0.0, 1.0]);
+    assert_colour("hsla(120, 100%, 50%, 0.25)", [0.0, 1.0, 0.0, 0.25]);
+    assert_colour("hwb(0 0% 0%)", [1.0, 0.0, 0.0, 1.0]);
+    assert_colour("hwb(0 50% 50%)", [0.5, 0.5, 0.5, 1.0]);
+}
+
+#[test]
+fn hue_units() {
+    let cyan = [0.0, 1.0, 1.0, 1.0];
+    for hue in [
+        "180",
+        "180deg",
+        "200grad",
+        "3.14159265rad",
+        "0.5turn",
+        "-180",
+        "540deg",
+    ] {
+        assert_colour(&format!("hsl({} 100% 50%)", hue), cyan);
+    }
+}
+
+#[test]
+fn percentage_scales() {
+    assert_same("lab(50% 100% -100%)", "lab(50 125 -125)");
+    assert_same("lch(50% 100% 90)", "lch(50 150 90)");
+    assert_same("oklab(50% 100% -100%)", "oklab(0.5 0.4 -0.4)");
+    assert_same("oklch(50% 100% 90)", "oklch(0.5 0.4 90)");
+    assert_same("color(srgb 100% 50% 0%)", "color(srgb 1 0.5 0)");
+}
+
+#[test]
+fn lab_like_functions() {
+    assert_colour("lab(100 0 0)", [1.0, 1.0, 1.0, 1.0]);
+    assert_colour("lch(0 0 0)", [0.0, 0.0, 0.0, 1.0]);
+    assert_colour("oklab(1 0 0)", [1.0, 1.0, 1.0, 1.0]);
+    assert_colour("oklch(0 0 30 / 0.5)", [0.0, 0.0, 0.0, 0.5]);
+    assert_same("lab(-10 0 0)", "lab(0 0 0)");
+    assert_same("oklch(0.5 -0.1 30)", "oklch(0.5 0 30)");
+}
+
+#[test]
+fn color_spaces() {
+    assert_colour("color(srgb 1 0.5 0)", [1.0, 0.5, 0.0, 1.0]);
+    assert_colour("color(srgb-linear 1 0.214041 0)", [1.0, 0.5, 0.0, 1.0]);
+    assert_colour(
+        "color(display-p3 1 0 0)",
+        [1.09307, -0.22674, -0.15013, 1.0],
+    );
+    assert_colour("color(xyz 0.950456 1 1.089058)", [1.0, 1.0, 1.0, 1.0]);
+    assert_colour("color(xyz-d65 0 0 0 / 10%)", [0.0, 0.0, 0.0, 0.1]);
+    assert_colour("color(xyz-d50 0.964296 1 0.825105)", [1.0, 1.0, 1.0, 1.0]);
+    for space in ["a98-rgb", "prophoto-rgb", "rec2020"] {
+        assert_colour(&format!("color({} 1 1 1)", space), [1.0, 1.0, 1.0, 1.0]);
+    }
+}
+
+#[test]
+fn out_of_gamut_colours_keep_their_channels() {
+    let colour = parse_css_colour("color(display-p3 0 1 0)").unwrap();
+    assert!(!colour.in_srgb_gamut());
+    assert!(CssColour::new(0.5, 0.5, 0.5, 1.0).in_srgb_gamut());
+}
+
+#[test]
+fn errors_and_spans() {
+    let cases = [
+        ("", ColourParseError::Empty),
+        ("   ", ColourParseError::Empty),
+        ("#", ColourParseError::Empty),
+        ("#12345", ColourParseError::InvalidLength { digits: 5 }),
+        (
+            "#12g",
+            ColourParseError::InvalidDigit {
+                found: 'g',
+                position: 3,
+            },
+        ),
+        ("rgb(255 0", ColourParseError::UnexpectedEnd { position: 9 }),
+        ("rgb(255 0 0) blue", unexpected("end of input", 13..17)),
+        ("rgb(255 0)", unexpected("a colour component", 9..10)),
+        ("hsl(1e999 50% 50%)", unexpected("a number", 4..9)),
+        ("rgb(1 2 3 / 1e999)", unexpected("a number", 12..17)),
+        (
+            "hsl(120 50deg 50%)",
+            ColourParseError::InvalidUnit {
+                unit: "deg".to_string(),
+                span: 8..13,
+            },
+        ),
+        (
+            "hsl(120px 50% 50%)",
+            ColourParseError::InvalidUnit {
+                unit: "px".to_string(),
+                span: 4..9,
+            },
+        ),
+        ("hsl(50% 50% 50%)", unexpected("a number", 4..7)),
+        (
+            "rgb(1 2 3) !",
+            ColourParseError::InvalidCharacter {
+                found: '!',
+                position: 11,
+            },
+        ),
+        (
+            "foo(1 2 3)",
+            ColourParseError::UnknownFunction {
+                name: "foo".to_string(),
+                span: 0..4,
+            },
+        ),
+        (
+            "notacolour",
+            ColourParseError::UnknownKeyword {
+                name: "notacolour".to_string(),
+                span: 0..10,
+            },
+        ),
+        (
+            "color(cmyk 1 0 0)",
+            ColourParseError::UnknownColourSpace {
+                name: "cmyk".to_string(),
+                span: 6..10,
+            },
+        ),
+        ("color(1 0 0)", unexpected("a colour space", 6..7)),
+        ("/", unexpected("a colour", 0..1)),
+    ];
+
+    for (css, expected) in cases {
+        assert_eq!(parse_css_colour(css), Err(expected), "{:?}", css);
+    }
+}
+
+#[test]
+fn error_spans_and_messages() {
+    let error = parse_css_colour("rgb(255, 0,, 0)").unwrap_err();
+    assert_eq!(error.span(), Some(11..12));
+    assert_eq!(error.to_string(), "expected a colour component at 11..12");
+
+    let error = parse_css_colour("#€00").unwrap_err();
+    assert_eq!(error.span(), Some(1..4));
+    assert_eq!(ColourParseError::Empty.span(), None);
+}
@@ -0,0 +1,34 @@
+use colour::{gradient::InterpolationSpace, Gradient, Rgb, RGB};
+
+#[test]
+fn widening_round_trips() {
+    for v in 0..=255 {
+        let rgb = RGB::new(v, v, v);
+        assert_eq!(Rgb::<u16>::from(rgb).convert::<u8>(), rgb);
+        assert_eq!(Rgb::<f32>::from(rgb).convert::<u8>(), rgb);
+        assert_eq!(Rgb::<f64>::from(rgb).convert::<u8>(), rgb);
+    }
+}
+
+#[test]
+fn u8_to_u16_replicates_the_byte() {
+    assert_eq!(Rgb::<u16>::from(RGB::new(0, 0x80, 0xff)), Rgb::new(0, 0x8080, 0xffff));
+}
+
+#[test]
+fn narrowing_rounds_to_nearest() {
+    // 0x8080 is exactly 0x80, and 0x8100 and 0x8101 sit just either side of
+    // halfway between 0x80 and 0x81.
+    assert_eq!(Rgb::<u16>::new(0x8080, 0x8100, 0x8101).convert::<u8>(), RGB::new(0x80, 0x80, 0x81));
+    assert_eq!(Rgb::<f64>::new(-0.5, 0.5, 1.5).convert::<u8>(), RGB::new(0, 128, 255));
+}
+
+#[test]
+fn u16_gradient_hits_both_endpoints() {
+    let (start, end) = (Rgb::<u16>::new(1, 2, 3), Rgb::<u16>::new(65534, 40000, 12345));
+    for &space in &[InterpolationSpace::Srgb, InterpolationSpace::LinearSrgb, InterpolationSpace::Oklab] {
+        let gradient = Gradient::new(start, end, 2, space);
+        assert_eq!(gradient.sample_as::<u16>(0.0), start);
+        assert_eq!(gradient.sample_as::<u16>(1.0), end);
+    }
+}
@@ -0,0 +1,132 @@
+use colour::{
+    colour::Rgb,
+    gradient::InterpolationSpace,
+    render::{Dither, RenderOptions},
+    Gradient, RGB,
+};
+
+const ALL: [Dither; 7] = [
+    Dither::None,
+    Dither::Bayer2,
+    Dither::Bayer4,
+    Dither::Bayer8,
+    Dither::BlueNoise,
+    Dither::FloydSteinberg,
+    Dither::Triangular,
+];
+
+/// A gradient of a single colour, `level` out of 255 in every channel.
+fn flat(level: f64) -> Gradient {
+    let grey = Rgb::<f64>::new(level / 255.0, level / 255.0, level / 255.0);
+    Gradient::new(grey, grey, 2, InterpolationSpace::Srgb)
+}
+
+fn mean(levels: &[u8]) -> f64 {
+    levels.iter().map(|&c| c as f64).sum::<f64>() / levels.len() as f64
+}
+
+#[test]
+fn no_dither_is_plain_rounding() {
+    let gradient = Gradient::new(
+        RGB::new(3, 20, 250),
+        RGB::new(240, 128, 9),
+        2,
+        InterpolationSpace::Oklab,
+    );
+    let options = RenderOptions::new(3, 1000).with_dither(Dither::None);
+    let exact = gradient.render_as::<f64>(&options);
+
+    let eight = gradient.render(&options);
+    for (pixel, exact) in eight.pixels().zip(exact.pixels()) {
+        assert_eq!(
+            pixel.0,
+            exact.0.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+        );
+    }
+    let sixteen = gradient.render_as::<u16>(&options);
+    for (pixel, exact) in sixteen.pixels().zip(exact.pixels()) {
+        assert_eq!(
+            pixel.0,
+            exact
+                .0
+                .map(|c| (c.clamp(0.0, 1.0) * 65535.0).round() as u16)
+        );
+    }
+}
+
+#[test]
+fn flat_grey_keeps_its_mean_level() {
+    // A quarter level, which every threshold map here can represent exactly.
+    let level = 127.25;
+    for dither in ALL.iter().copied().filter(|&d| d != Dither::None) {
+        let image = flat(level).render(&RenderOptions::new(64, 64).with_dither(dither));
+        let levels = image.into_raw();
+        // Triangular noise spans a level either way, so it may reach one
+        // level further than the others.
+        let range = if dither == Dither::Triangular {
+            126..=129
+        } else {
+            127..=128
+        };
+        assert!(levels.iter().all(|c| range.contains(c)), "{:?}", dither);
+        // Ordered dithers are exact over a whole tile; error diffusion loses
+        // a little off the image's edges, and noise is noise.
+        let tolerance = match dither {
+            Dither::FloydSteinberg => 0.01,
+            Dither::Triangular => 0.05,
+            _ => 1e-9,
+        };
+        assert!(
+            (mean(&levels) - level).abs() < tolerance,
+            "{:?}: {}",
+            dither,
+            mean(&levels)
+        );
+    }
+}
+
+#[test]
+fn whole_levels_are_left_alone() {
+    for dither in ALL.iter().copied().filter(|&d| d != Dither::Triangular) {
+        let image = flat(200.0).render(&RenderOptions::new(16, 16).with_dither(dither));
+        assert!(image.into_raw().iter().all(|&c| c == 200), "{:?}", dither);
+    }
+}
+
+#[test]
+fn bayer2_fills_in_rank_order() {
+    // The 2×2 matrix ranks its cells, row by row, 0, 2, 3 and 1, so a
+    // growing fraction of a level rounds up cell by cell in that order.
+    let cases = [
+        (0.1, [false, false, false, false]),
+        (0.2, [false, false, true, false]),
+        (0.4, [false, true, true, false]),
+        (0.7, [false, true, true, true]),
+        (0.9, [true, true, true, true]),
+    ];
+    for (fraction, expected) in cases {
+        let image =
+            flat(100.0 + fraction).render(&RenderOptions::new(2, 2).with_dither(Dither::Bayer2));
+        let up: Vec<bool> = image.pixels().map(|p| p.0[0] == 101).collect();
+        assert_eq!(up, expected, "{}", fraction);
+    }
+}
+
+#[test]
+fn seeded_dithers_are_deterministic() {
+    let gradient = Gradient::new(
+        RGB::new(10, 20, 30),
+        RGB::new(40, 50, 60),
+        2,
+        InterpolationSpace::Srgb,
+    );
+    for dither in ALL {
+        let options = RenderOptions::new(70, 300).with_dither(dither);
+        assert_eq!(
+            gradient.render(&options),
+            gradient.render(&options),
+            "{:?}",
+            dither
+        );
+    }
+}
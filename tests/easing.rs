@@ -0,0 +1,161 @@
+use colour::gradient::{Easing, StepPosition};
+
+fn assert_close(actual: f64, expected: f64, what: &str) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "{}: {} != {}",
+        what,
+        actual,
+        expected
+    );
+}
+
+/// The Bézier curve's `y` at `x = t`, found by plain bisection on the
+/// parameter.
+fn reference_bezier(x1: f64, y1: f64, x2: f64, y2: f64, t: f64) -> f64 {
+    let point = |p1: f64, p2: f64, s: f64| {
+        3.0 * (1.0 - s).powi(2) * s * p1 + 3.0 * (1.0 - s) * s * s * p2 + s.powi(3)
+    };
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..200 {
+        let mid = (lo + hi) / 2.0;
+        if point(x1, x2, mid) < t {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    point(y1, y2, (lo + hi) / 2.0)
+}
+
+#[test]
+fn every_curve_starts_at_zero_and_ends_at_one() {
+    let curves = [
+        Easing::Linear,
+        Easing::Ease,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+        Easing::SmoothStep,
+        Easing::CubicBezier(0.5, -0.5, 0.5, 1.5),
+    ];
+    for easing in curves {
+        assert_close(easing.apply(0.0), 0.0, &format!("{:?}", easing));
+        assert_close(easing.apply(1.0), 1.0, &format!("{:?}", easing));
+    }
+}
+
+#[test]
+fn named_curves_match_known_values() {
+    assert_close(Easing::Linear.apply(0.3), 0.3, "linear");
+    assert_close(Easing::EaseInOut.apply(0.5), 0.5, "ease-in-out");
+    assert_close(Easing::SmoothStep.apply(0.25), 0.15625, "smoothstep");
+    assert!((Easing::Ease.apply(0.5) - 0.8024).abs() < 1e-4);
+    assert!((Easing::EaseIn.apply(0.5) - 0.3154).abs() < 1e-4);
+
+    // ease-out is ease-in turned around.
+    for i in 0..=20 {
+        let t = i as f64 / 20.0;
+        let mirrored = 1.0 - Easing::EaseIn.apply(1.0 - t);
+        assert_close(
+            Easing::EaseOut.apply(t),
+            mirrored,
+            &format!("ease-out at {}", t),
+        );
+    }
+}
+
+#[test]
+fn cubic_bezier_matches_bisection() {
+    let curves = [
+        (0.25, 0.1, 0.25, 1.0),
+        (0.42, 0.0, 0.58, 1.0),
+        (1.0, 0.0, 0.0, 1.0),
+        (0.0, 0.0, 0.0, 1.0),
+        (0.9, 0.1, 1.0, 0.2),
+        (0.5, -0.5, 0.5, 1.5),
+        (0.3, 2.0, 0.7, -1.0),
+    ];
+    for (x1, y1, x2, y2) in curves {
+        for i in 0..=100 {
+            let t = i as f64 / 100.0;
+            let actual = Easing::CubicBezier(x1, y1, x2, y2).apply(t);
+            let expected = reference_bezier(x1, y1, x2, y2, t);
+            // Where x(s) is flat, as at the middle of (1, 0, 0, 1), x pins s
+            // down only to about the cube root of its own precision.
+            assert!(
+                (actual - expected).abs() < 1e-4,
+                "cubic-bezier({}, {}, {}, {}) at {}: {} != {}",
+                x1,
+                y1,
+                x2,
+                y2,
+                t,
+                actual,
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn cubic_bezier_y_overshoots_and_x_is_clamped() {
+    let overshoot = Easing::CubicBezier(0.5, -0.5, 0.5, 1.5);
+    let values: Vec<f64> = (0..=100)
+        .map(|i| overshoot.apply(i as f64 / 100.0))
+        .collect();
+    assert!(values.iter().any(|&y| y < 0.0));
+    assert!(values.iter().any(|&y| y > 1.0));
+    assert_close(overshoot.apply(0.5), 0.5, "symmetric overshoot");
+
+    let clamped = Easing::CubicBezier(-1.0, 0.0, 2.0, 1.0);
+    let corners = Easing::CubicBezier(0.0, 0.0, 1.0, 1.0);
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        assert_close(
+            clamped.apply(t),
+            corners.apply(t),
+            &format!("clamped at {}", t),
+        );
+    }
+}
+
+#[test]
+fn steps_for_every_position() {
+    let cases = [
+        (StepPosition::JumpEnd, [0.0, 0.0, 0.25, 0.25, 0.75, 1.0]),
+        (StepPosition::JumpStart, [0.25, 0.25, 0.5, 0.5, 1.0, 1.0]),
+        (
+            StepPosition::JumpNone,
+            [0.0, 0.0, 1.0 / 3.0, 1.0 / 3.0, 1.0, 1.0],
+        ),
+        (StepPosition::JumpBoth, [0.2, 0.2, 0.4, 0.4, 0.8, 1.0]),
+    ];
+    let ts = [0.0, 0.2, 0.25, 0.3, 0.99, 1.0];
+    for (position, expected) in cases {
+        let easing = Easing::Steps(4, position);
+        for (t, e) in ts.iter().zip(&expected) {
+            assert_close(
+                easing.apply(*t),
+                *e,
+                &format!("steps(4, {:?}) at {}", position, t),
+            );
+        }
+    }
+    assert_eq!(StepPosition::default(), StepPosition::JumpEnd);
+}
+
+#[test]
+fn invalid_steps_are_linear() {
+    for easing in [
+        Easing::Steps(0, StepPosition::JumpEnd),
+        Easing::Steps(1, StepPosition::JumpNone),
+    ] {
+        assert_close(easing.apply(0.3), 0.3, &format!("{:?}", easing));
+    }
+    assert_close(
+        Easing::Steps(1, StepPosition::JumpEnd).apply(0.5),
+        0.0,
+        "steps(1)",
+    );
+}
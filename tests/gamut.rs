@@ -0,0 +1,146 @@
+use colour::{
+    colour::{DisplayP3, GamutMapping, LinearRgb, Oklch, Rec2020, Srgb, WideRgb},
+    parse_css_colour, RGB,
+};
+
+const STRATEGIES: [GamutMapping; 3] = [GamutMapping::Clip, GamutMapping::Scale, GamutMapping::Css];
+
+#[test]
+fn in_gamut_colours_are_unchanged() {
+    let colour = WideRgb::<DisplayP3>::new(0.2, 0.5, 0.8);
+    assert!(colour.in_gamut());
+    for strategy in STRATEGIES {
+        assert_eq!(colour.to_gamut(strategy), colour);
+    }
+}
+
+#[test]
+fn mapped_colours_are_in_gamut() {
+    let colours = [
+        WideRgb::<DisplayP3>::new(1.0, 0.0, 0.0),
+        WideRgb::<DisplayP3>::new(0.0, 1.0, 0.0),
+        WideRgb::<DisplayP3>::new(0.0, 0.0, 1.0),
+    ];
+    for colour in colours {
+        let srgb = colour.convert::<Srgb>();
+        assert!(!srgb.in_gamut());
+        for strategy in STRATEGIES {
+            assert!(srgb.to_gamut(strategy).in_gamut(), "{:?} {:?}", colour, strategy);
+        }
+    }
+}
+
+#[test]
+fn css_mapping_keeps_hue_and_lightness() {
+    let colour = parse_css_colour("oklch(0.7 0.4 40)").unwrap();
+    assert!(!colour.in_srgb_gamut());
+    let mapped = colour.to_gamut(GamutMapping::Css);
+    assert!(mapped.in_srgb_gamut());
+
+    let rgb = mapped.to_rgb();
+    let lch = Oklch::from(rgb);
+    assert!((lch.h - 40.0).abs() < 2.0, "{:?}", lch);
+    assert!((lch.l - 0.7).abs() < 0.02, "{:?}", lch);
+    assert_eq!(RGB::from_css_string("oklch(0.7 0.4 40)").unwrap(), rgb);
+}
+
+#[test]
+fn css_mapping_into_wide_spaces() {
+    let colour = WideRgb::<Rec2020>::new(0.0, 1.0, 0.0).convert::<DisplayP3>();
+    assert!(!colour.in_gamut());
+    assert!(colour.to_gamut(GamutMapping::Css).in_gamut());
+}
+
+#[test]
+fn too_light_and_too_dark_become_white_and_black() {
+    assert_eq!(RGB::from(Oklch::new(1.1, 0.2, 120.0)), RGB::new(255, 255, 255));
+    assert_eq!(RGB::from(Oklch::new(-0.1, 0.2, 120.0)), RGB::new(0, 0, 0));
+}
+
+#[test]
+fn linear_rgb_is_mapped_like_the_other_spaces() {
+    let (red, green, blue) = (1.2, 0.1, -0.05);
+    let linear = LinearRgb::new(red, green, blue);
+    let wide = WideRgb::<Srgb>::from_linear([red, green, blue]);
+    assert!(!wide.in_gamut());
+    assert_eq!(RGB::from(linear), wide.to_rgb());
+    // Clipping each channel would give this instead.
+    assert_ne!(RGB::from(linear), RGB::new(255, 89, 0));
+}
+
+#[test]
+fn extreme_css_values_map_by_their_lightness() {
+    let white = RGB::new(255, 255, 255);
+    for css in [
+        "color(display-p3 1e300 0 0)",
+        "lab(1e308 1e308 1e308)",
+        "lch(50 1e305 0)",
+        "oklch(1 1e300 1e300)",
+        "color(xyz 1e308 1e308 1e308)",
+    ] {
+        assert_eq!(RGB::from_css_string(css).unwrap(), white, "{}", css);
+    }
+    let black = RGB::new(0, 0, 0);
+    for css in [
+        "color(display-p3 -1e300 -1e300 -1e300)",
+        "color(srgb-linear -1e308 -1e308 -1e308)",
+        "color(xyz-d50 -1e308 -1e308 -1e308)",
+    ] {
+        assert_eq!(RGB::from_css_string(css).unwrap(), black, "{}", css);
+    }
+}
+
+#[test]
+fn css_mapping_starts_from_the_parsed_colour() {
+    // A chroma far too large to convert maps like any other chroma past the
+    // gamut's edge, keeping its lightness and hue.
+    let huge = RGB::from_css_string("oklch(0.6 1e200 150)").unwrap();
+    let large = RGB::from_css_string("oklch(0.6 0.5 150)").unwrap();
+    let (a, b) = (huge.to_tuple(), large.to_tuple());
+    for (x, y) in [(a.0, b.0), (a.1, b.1), (a.2, b.2)] {
+        assert!((x as i32 - y as i32).abs() <= 1, "{:?} != {:?}", huge, large);
+    }
+}
+
+#[test]
+fn nan_channels_count_as_zero() {
+    let colour = WideRgb::<Srgb>::new(f64::NAN, 0.5, 2.0);
+    assert_eq!(
+        colour.to_gamut(GamutMapping::Clip),
+        WideRgb::new(0.0, 0.5, 1.0)
+    );
+    for strategy in STRATEGIES {
+        let mapped = colour.to_gamut(strategy);
+        assert!(mapped.in_gamut(), "{:?} {:?}", strategy, mapped);
+    }
+    let grey = WideRgb::<Srgb>::new(0.5, f64::NAN, 0.5);
+    for strategy in STRATEGIES {
+        assert_eq!(grey.to_gamut(strategy), WideRgb::new(0.5, 0.0, 0.5));
+    }
+}
+
+#[test]
+fn overflowing_colours_become_white_or_black() {
+    let (white, black) = (
+        WideRgb::<Rec2020>::new(1.0, 1.0, 1.0),
+        WideRgb::<Rec2020>::new(0.0, 0.0, 0.0),
+    );
+    for strategy in [GamutMapping::Scale, GamutMapping::Css] {
+        for (colour, expected) in [
+            (WideRgb::<Rec2020>::new(1e300, 0.0, 0.0), white),
+            (WideRgb::new(f64::INFINITY, 0.5, 0.0), white),
+            (WideRgb::new(-1e300, -1e300, -1e300), black),
+            (WideRgb::new(f64::NEG_INFINITY, 0.0, 0.0), black),
+        ] {
+            let mapped = colour.to_gamut(strategy);
+            let close = [
+                (mapped.red, expected.red),
+                (mapped.green, expected.green),
+                (mapped.blue, expected.blue),
+            ]
+            .iter()
+            .all(|(a, e)| (a - e).abs() < 1e-9);
+            assert!(close, "{:?} {:?}: {:?}", strategy, colour, mapped);
+        }
+    }
+}
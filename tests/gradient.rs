@@ -0,0 +1,197 @@
+use colour::{
+    colour::{GamutMapping, Hsl, LinearRgb, Oklch, Rgb, Srgb, WideRgb},
+    gradient::{HueInterpolation, InterpolationSpace},
+    render::{Orientation, RenderOptions},
+    Gradient, RGB,
+};
+
+mod common;
+
+fn spaces() -> Vec<InterpolationSpace> {
+    let mut spaces = vec![
+        InterpolationSpace::Srgb,
+        InterpolationSpace::LinearSrgb,
+        InterpolationSpace::Lab,
+        InterpolationSpace::Oklab,
+    ];
+    for &method in &[
+        HueInterpolation::Shorter,
+        HueInterpolation::Longer,
+        HueInterpolation::Increasing,
+        HueInterpolation::Decreasing,
+    ] {
+        spaces.push(InterpolationSpace::Hsl(method));
+        spaces.push(InterpolationSpace::Lch(method));
+        spaces.push(InterpolationSpace::Oklch(method));
+    }
+    spaces
+}
+
+#[test]
+fn sample_hits_both_endpoints() {
+    for space in spaces() {
+        for (start, end) in common::pairs(500) {
+            let gradient = Gradient::new(start, end, 2, space);
+            assert_eq!(gradient.sample(0.0), start, "{:?}", space);
+            assert_eq!(gradient.sample(1.0), end, "{:?}", space);
+        }
+    }
+}
+
+#[test]
+fn generated_steps_hit_both_endpoints() {
+    for space in spaces() {
+        for (start, end) in common::pairs(500) {
+            for &steps in &[2, 3, 256] {
+                let colours: Vec<RGB> = Gradient::new(start, end, steps, space).into_iter().collect();
+                assert_eq!(colours.len(), steps);
+                assert_eq!(colours[0], start, "{:?}", space);
+                assert_eq!(colours[steps - 1], end, "{:?}", space);
+            }
+        }
+    }
+}
+
+#[test]
+fn sample_n_hits_both_endpoints() {
+    let (start, end) = (RGB::new(12, 34, 56), RGB::new(210, 190, 170));
+    for space in spaces() {
+        let colours = Gradient::new(start, end, 2, space).sample_n(7);
+        assert_eq!(colours.first(), Some(&start));
+        assert_eq!(colours.last(), Some(&end));
+    }
+}
+
+#[test]
+fn rendered_edges_are_the_endpoints() {
+    let (start, end) = (RGB::new(255, 0, 0), RGB::new(0, 0, 255));
+    let image = Gradient::new(start, end, 2, InterpolationSpace::Oklab).render(&RenderOptions::new(4, 100));
+    let (r, g, b) = start.to_tuple();
+    assert_eq!(image.get_pixel(0, 0).0, [r, g, b]);
+    let (r, g, b) = end.to_tuple();
+    assert_eq!(image.get_pixel(3, 99).0, [r, g, b]);
+}
+
+fn hsl_midpoint(from: Hsl, to: Hsl, method: HueInterpolation) -> RGB {
+    Gradient::new(RGB::from(from), RGB::from(to), 2, InterpolationSpace::Hsl(method)).sample(0.5)
+}
+
+fn hue(hue: f64) -> RGB {
+    RGB::from(Hsl::new(hue, 1.0, 0.5))
+}
+
+#[test]
+fn midpoint_hue_follows_the_interpolation_method() {
+    let (red, blue) = (Hsl::new(0.0, 1.0, 0.5), Hsl::new(240.0, 1.0, 0.5));
+    let cases = [
+        (red, blue, HueInterpolation::Shorter, 300.0),
+        (blue, red, HueInterpolation::Shorter, 300.0),
+        (red, blue, HueInterpolation::Longer, 120.0),
+        (blue, red, HueInterpolation::Longer, 120.0),
+        (red, blue, HueInterpolation::Increasing, 120.0),
+        (blue, red, HueInterpolation::Increasing, 300.0),
+        (red, blue, HueInterpolation::Decreasing, 300.0),
+        (blue, red, HueInterpolation::Decreasing, 120.0),
+    ];
+    for (from, to, method, expected) in cases {
+        let mid = hsl_midpoint(from, to, method);
+        assert_eq!(mid, hue(expected), "{} to {}, {:?}", from.hue, to.hue, method);
+    }
+}
+
+#[test]
+fn opposite_hues_interpolate_without_adjustment() {
+    // With a difference of exactly ±180°, neither shorter nor longer adds a
+    // turn, so both go the way the hues are written.
+    let (red, cyan) = (Hsl::new(0.0, 1.0, 0.5), Hsl::new(180.0, 1.0, 0.5));
+    for method in [HueInterpolation::Shorter, HueInterpolation::Longer] {
+        assert_eq!(hsl_midpoint(red, cyan, method), hue(90.0), "{:?}", method);
+        assert_eq!(hsl_midpoint(cyan, red, method), hue(90.0), "{:?}", method);
+    }
+    assert_eq!(hsl_midpoint(red, cyan, HueInterpolation::Increasing), hue(90.0));
+    assert_eq!(hsl_midpoint(red, cyan, HueInterpolation::Decreasing), hue(270.0));
+    assert_eq!(hsl_midpoint(cyan, red, HueInterpolation::Increasing), hue(270.0));
+}
+
+#[test]
+fn grey_endpoints_take_the_other_hue() {
+    let (grey, blue) = (RGB::new(128, 128, 128), RGB::new(0, 0, 255));
+    for method in [
+        HueInterpolation::Shorter,
+        HueInterpolation::Increasing,
+        HueInterpolation::Decreasing,
+    ] {
+        for (from, to) in [(grey, blue), (blue, grey)] {
+            let mid = Gradient::new(from, to, 2, InterpolationSpace::Hsl(method)).sample(0.5);
+            assert_eq!(Hsl::from(mid).hue.round(), 240.0, "{:?} {:?}", method, mid);
+        }
+    }
+
+    // Both hues are then equal, and longer takes a whole turn, as CSS does.
+    let longer = InterpolationSpace::Hsl(HueInterpolation::Longer);
+    let mid = Gradient::new(grey, blue, 2, longer).sample(0.5);
+    assert_eq!(Hsl::from(mid).hue.round(), 60.0, "{:?}", mid);
+}
+
+#[test]
+fn oklch_midpoint_hue() {
+    let oklch = |rgb: Rgb<f64>| {
+        let (r, g, b) = rgb.to_tuple();
+        let linear = LinearRgb::from_srgb([r, g, b]);
+        Oklch::from_linear_srgb([linear.red, linear.green, linear.blue])
+    };
+    let (red, blue) = (RGB::new(255, 0, 0), RGB::new(0, 0, 255));
+    let (h_red, h_blue) = (Oklch::from(red).h, Oklch::from(blue).h);
+    assert!(h_blue - h_red > 180.0);
+
+    let cases = [
+        (HueInterpolation::Shorter, (h_red + 360.0 + h_blue) / 2.0),
+        (HueInterpolation::Longer, (h_red + h_blue) / 2.0),
+        (HueInterpolation::Increasing, (h_red + h_blue) / 2.0),
+        (HueInterpolation::Decreasing, (h_red + 360.0 + h_blue) / 2.0),
+    ];
+    for (method, expected) in cases {
+        let gradient = Gradient::new(red, blue, 2, InterpolationSpace::Oklch(method));
+        let mid = gradient.sample_as::<f64>(0.5);
+        let h = oklch(mid).h;
+        assert!((h - expected).abs() < 1e-4, "{:?}: {} != {}", method, h, expected);
+    }
+}
+
+#[test]
+fn out_of_gamut_midpoints_are_gamut_mapped() {
+    // The longer way from red to blue in Oklch passes through greens that
+    // sRGB cannot show: the midpoint decodes to about (-0.36, 0.58, -0.23),
+    // which clipping channel by channel would turn into #009300.
+    let (red, blue) = (RGB::new(255, 0, 0), RGB::new(0, 0, 255));
+    let space = InterpolationSpace::Oklch(HueInterpolation::Longer);
+    let gradient = Gradient::new(red, blue, 3, space);
+    let exact = gradient.sample_as::<f64>(0.5);
+    let (r, g, b) = exact.to_tuple();
+    assert!(r < -0.3 && g > 0.5 && b < -0.2, "{:?}", exact);
+
+    let clipped = RGB::new(0, 0x93, 0);
+    let mapped = WideRgb::<Srgb>::new(r, g, b).to_gamut(GamutMapping::Css);
+    let mapped = Rgb::<f64>::new(mapped.red, mapped.green, mapped.blue).convert::<u8>();
+    assert_ne!(mapped, clipped);
+    assert_eq!(gradient.sample(0.5), mapped);
+    assert_eq!(gradient.sample_rgba(0.5).rgb(), mapped);
+    assert_eq!(gradient.sample_as::<u16>(0.5).convert::<u8>(), mapped);
+    let steps: Vec<RGB> = gradient.into_iter().collect();
+    assert_eq!(steps[1], mapped);
+
+    // Mapping keeps the lightness halfway along, to within a just noticeable
+    // difference, where clipping lightens the colour.
+    let expected = (Oklch::from(red).l + Oklch::from(blue).l) / 2.0;
+    let (kept, moved) = (Oklch::from(mapped).l, Oklch::from(clipped).l);
+    assert!((kept - expected).abs() < 0.02, "{} != {}", kept, expected);
+    assert!(moved - expected > 0.03, "{} == {}", moved, expected);
+
+    let gradient = Gradient::new(red, blue, 2, space);
+    let options = RenderOptions::new(3, 1).with_orientation(Orientation::Horizontal);
+    let (r, g, b) = mapped.to_tuple();
+    assert_eq!(gradient.render(&options).get_pixel(1, 0).0, [r, g, b]);
+    let (r, g, b) = clipped.to_tuple();
+    let clip = options.with_gamut_mapping(GamutMapping::Clip);
+    assert_eq!(gradient.render(&clip).get_pixel(1, 0).0, [r, g, b]);
+}
@@ -0,0 +1,102 @@
+use colour::{colour::Rgba, ColourParseError, RGB};
+
+use std::convert::TryFrom;
+
+#[test]
+fn every_length_parses() {
+    let orange = RGB::new(0xff, 0x88, 0x00);
+    for hex in [
+        "#f80",
+        "#f80c",
+        "#ff8800",
+        "#ff8800cc",
+        "f80",
+        "FF8800",
+        "fF8800Cc",
+    ] {
+        assert_eq!(RGB::from_hex_string(hex), Ok(orange), "{:?}", hex);
+    }
+
+    assert_eq!(
+        Rgba::from_hex_string("#f80c"),
+        Ok(Rgba::new(0xff, 0x88, 0x00, 0xcc))
+    );
+    assert_eq!(
+        Rgba::from_hex_string("#ff880080"),
+        Ok(Rgba::new(0xff, 0x88, 0x00, 0x80))
+    );
+    assert_eq!(
+        Rgba::from_hex_string("#ff8800"),
+        Ok(Rgba::new(0xff, 0x88, 0x00, 0xff))
+    );
+}
+
+#[test]
+fn from_str_try_from_and_display_round_trip() {
+    let colour = RGB::new(0x12, 0xab, 0xef);
+    assert_eq!(colour.to_string(), "#12abef");
+    assert_eq!("#12abef".parse::<RGB>(), Ok(colour));
+    assert_eq!(RGB::try_from("12ABEF"), Ok(colour));
+
+    let rgba = Rgba::new(0x12, 0xab, 0xef, 0x34);
+    assert_eq!(rgba.to_string(), "#12abef34");
+    assert_eq!(rgba.to_string().parse::<Rgba>(), Ok(rgba));
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(RGB::from_hex_string(""), Err(ColourParseError::Empty));
+    assert_eq!(RGB::from_hex_string("#"), Err(ColourParseError::Empty));
+}
+
+#[test]
+fn wrong_lengths() {
+    for (hex, digits) in [
+        ("#f", 1),
+        ("#ff", 2),
+        ("#ff880", 5),
+        ("ff88000", 7),
+        ("#ff8800ccd", 9),
+    ] {
+        assert_eq!(
+            RGB::from_hex_string(hex),
+            Err(ColourParseError::InvalidLength { digits }),
+            "{:?}",
+            hex
+        );
+    }
+}
+
+#[test]
+fn invalid_digits_report_byte_positions() {
+    let cases = [
+        ("#ff880g", 'g', 6),
+        ("ff880g", 'g', 5),
+        ("#-f8800", '-', 1),
+        ("# f8800", ' ', 1),
+        ("##f80", '#', 1),
+        ("#f€80", '€', 2),
+        ("#ff8€", '€', 4),
+    ];
+    for (hex, found, position) in cases {
+        let error = RGB::from_hex_string(hex).unwrap_err();
+        assert_eq!(
+            error,
+            ColourParseError::InvalidDigit { found, position },
+            "{:?}",
+            hex
+        );
+        assert_eq!(&hex[error.span().unwrap()], found.to_string());
+    }
+}
+
+#[test]
+fn invalid_digits_are_found_before_the_length_is_checked() {
+    assert_eq!(
+        RGB::from_hex_string("#zz"),
+        Err(ColourParseError::InvalidDigit {
+            found: 'z',
+            position: 1
+        })
+    );
+}
@@ -0,0 +1,57 @@
+use colour::{
+    colour::{Hsl, Hsv},
+    RGB,
+};
+
+mod common;
+
+#[test]
+fn rgb_round_trips_through_hsl() {
+    for rgb in common::colours(100_000) {
+        assert_eq!(RGB::from(Hsl::from(rgb)), rgb, "via {:?}", Hsl::from(rgb));
+    }
+}
+
+#[test]
+fn rgb_round_trips_through_hsv() {
+    for rgb in common::colours(100_000) {
+        assert_eq!(RGB::from(Hsv::from(rgb)), rgb, "via {:?}", Hsv::from(rgb));
+    }
+}
+
+#[test]
+fn hsl_and_hsv_convert_between_each_other() {
+    for rgb in common::colours(100_000) {
+        assert_eq!(RGB::from(Hsv::from(Hsl::from(rgb))), rgb);
+        assert_eq!(RGB::from(Hsl::from(Hsv::from(rgb))), rgb);
+    }
+}
+
+#[test]
+fn greys_are_achromatic_with_zero_hue() {
+    for v in 0..=255 {
+        let grey = RGB::new(v, v, v);
+        let (hsl, hsv) = (Hsl::from(grey), Hsv::from(grey));
+        assert!(hsl.is_achromatic() && hsv.is_achromatic());
+        assert_eq!((hsl.hue, hsl.saturation), (0.0, 0.0));
+        assert_eq!((hsv.hue, hsv.saturation), (0.0, 0.0));
+    }
+}
+
+#[test]
+fn hue_is_ignored_for_greys() {
+    for hue in &[0.0, 90.0, 200.0, 359.0] {
+        assert_eq!(RGB::from(Hsl::new(*hue, 0.0, 0.5)), RGB::new(128, 128, 128));
+        assert_eq!(RGB::from(Hsv::new(*hue, 0.0, 0.5)), RGB::new(128, 128, 128));
+    }
+}
+
+#[test]
+fn known_values() {
+    let hsl = Hsl::from(RGB::new(255, 128, 0));
+    assert!((hsl.hue - 30.117_647).abs() < 1e-6);
+    assert_eq!(hsl.saturation, 1.0);
+    assert_eq!(RGB::from(Hsl::new(120.0, 1.0, 0.25)), RGB::new(0, 128, 0));
+    assert_eq!(RGB::from(Hsv::new(240.0, 1.0, 1.0)), RGB::new(0, 0, 255));
+    assert_eq!(RGB::from(Hsv::new(-120.0, 1.0, 1.0)), RGB::new(0, 0, 255));
+}
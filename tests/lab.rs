@@ -0,0 +1,107 @@
+use colour::{
+    colour::{Lab, Lch, WhitePoint, Xyz},
+    RGB,
+};
+
+mod common;
+
+fn assert_close(actual: [f64; 3], expected: [f64; 3], tolerance: f64) {
+    for (a, e) in actual.iter().zip(&expected) {
+        assert!((a - e).abs() < tolerance, "{:?} != {:?}", actual, expected);
+    }
+}
+
+/// CIE standard illuminant A, an incandescent white.
+const A: WhitePoint = WhitePoint::from_chromaticity(0.44757, 0.40745);
+
+#[test]
+fn red_matches_the_reference_values() {
+    let red = RGB::new(255, 0, 0);
+
+    let xyz = Xyz::from(red);
+    assert_close([xyz.x, xyz.y, xyz.z], [0.4124, 0.2126, 0.0193], 1e-4);
+
+    // CSS gives lab(54.29 80.8 69.89) relative to D50.
+    let lab = Lab::from(red);
+    assert_close([lab.l, lab.a, lab.b], [54.29, 80.80, 69.89], 0.01);
+    let lab = Lab::from_rgb(red, WhitePoint::D65);
+    assert_close([lab.l, lab.a, lab.b], [53.24, 80.09, 67.20], 0.01);
+
+    let lch = Lch::from(red);
+    assert_close([lch.l, lch.c, lch.h], [54.29, 106.84, 40.85], 0.01);
+}
+
+#[test]
+fn rgb_round_trips_through_lab() {
+    for rgb in common::colours(20_000) {
+        assert_eq!(RGB::from(Lab::from(rgb)), rgb, "via {:?}", Lab::from(rgb));
+        let d65 = Lab::from_rgb(rgb, WhitePoint::D65);
+        assert_eq!(d65.to_rgb(WhitePoint::D65), rgb, "via {:?}", d65);
+    }
+}
+
+#[test]
+fn rgb_round_trips_through_lch() {
+    for rgb in common::colours(20_000) {
+        assert_eq!(RGB::from(Lch::from(rgb)), rgb, "via {:?}", Lch::from(rgb));
+        let d65 = Lch::from_rgb(rgb, WhitePoint::D65);
+        assert_eq!(d65.to_rgb(WhitePoint::D65), rgb, "via {:?}", d65);
+    }
+}
+
+#[test]
+fn rgb_round_trips_through_xyz() {
+    for rgb in common::colours(20_000) {
+        assert_eq!(RGB::from(Xyz::from(rgb)), rgb, "via {:?}", Xyz::from(rgb));
+    }
+}
+
+#[test]
+fn adaptation_round_trips() {
+    for rgb in common::colours(1_000) {
+        let xyz = Xyz::from(rgb);
+        let there = xyz.adapt(WhitePoint::D65, WhitePoint::D50);
+        let back = there.adapt(WhitePoint::D50, WhitePoint::D65);
+        assert_close([back.x, back.y, back.z], [xyz.x, xyz.y, xyz.z], 1e-12);
+
+        let there = xyz.adapt(WhitePoint::D50, WhitePoint::D65);
+        let back = there.adapt(WhitePoint::D65, WhitePoint::D50);
+        assert_close([back.x, back.y, back.z], [xyz.x, xyz.y, xyz.z], 1e-12);
+    }
+
+    // Adapting carries one white onto the other.
+    let (d50, d65) = (WhitePoint::D50, WhitePoint::D65);
+    let white = Xyz::new(d65.x, d65.y, d65.z).adapt(d65, d50);
+    assert_close([white.x, white.y, white.z], [d50.x, d50.y, d50.z], 1e-12);
+}
+
+#[test]
+fn white_points_from_chromaticity() {
+    let d50 = WhitePoint::from_chromaticity(0.3457, 0.3585);
+    assert_eq!(d50, WhitePoint::D50);
+    assert_close([d50.x, d50.y, d50.z], [0.9642, 1.0, 0.8251], 1e-4);
+    let d65 = WhitePoint::D65;
+    assert_close([d65.x, d65.y, d65.z], [0.9505, 1.0, 1.0891], 1e-4);
+    assert_close([A.x, A.y, A.z], [1.0985, 1.0, 0.3558], 1e-4);
+}
+
+#[test]
+fn lab_works_relative_to_any_white() {
+    // White is adapted onto the white point, so it is neutral in Lab
+    // relative to that white.
+    let white = Lab::from_rgb(RGB::new(255, 255, 255), A);
+    assert_close([white.l, white.a, white.b], [100.0, 0.0, 0.0], 1e-9);
+    let lab_white = Xyz::from_lab(Lab::new(100.0, 0.0, 0.0), A);
+    assert_close(
+        [lab_white.x, lab_white.y, lab_white.z],
+        [A.x, A.y, A.z],
+        1e-12,
+    );
+
+    for rgb in common::colours(1_000) {
+        let lab = Lab::from_rgb(rgb, A);
+        assert_eq!(lab.to_rgb(A), rgb, "via {:?}", lab);
+        let lch = Lch::from_rgb(rgb, A);
+        assert_eq!(lch.to_rgb(A), rgb, "via {:?}", lch);
+    }
+}
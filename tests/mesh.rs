@@ -0,0 +1,139 @@
+use colour::{
+    gradient::{BilinearGradient, CoonsPatch, GradientError, InterpolationSpace, MeshGradient},
+    render::{PixelFormat, RenderOptions},
+    RGB,
+};
+
+const RED: RGB = RGB::new(255, 0, 0);
+const GREEN: RGB = RGB::new(0, 255, 0);
+const BLUE: RGB = RGB::new(0, 0, 255);
+const WHITE: RGB = RGB::new(255, 255, 255);
+
+fn rgb(colour: RGB) -> [u8; 3] {
+    let (r, g, b) = colour.to_tuple();
+    [r, g, b]
+}
+
+#[test]
+fn bilinear_corners_are_the_corner_colours() {
+    for space in [
+        InterpolationSpace::Srgb,
+        InterpolationSpace::LinearSrgb,
+        InterpolationSpace::Oklab,
+    ] {
+        let gradient = BilinearGradient::new(RED, GREEN, BLUE, WHITE, space);
+        assert_eq!(gradient.corners(), [RED, GREEN, WHITE, BLUE]);
+        assert_eq!(gradient.sample(0.0, 0.0), RED);
+        assert_eq!(gradient.sample(1.0, 0.0), GREEN);
+        assert_eq!(gradient.sample(0.0, 1.0), BLUE);
+        assert_eq!(gradient.sample(1.0, 1.0), WHITE);
+
+        for &(width, height) in &[(2, 2), (7, 5), (64, 3)] {
+            let image = gradient.render(&RenderOptions::new(width, height));
+            let (right, bottom) = (width - 1, height - 1);
+            assert_eq!(image.get_pixel(0, 0).0, rgb(RED), "{:?}", space);
+            assert_eq!(image.get_pixel(right, 0).0, rgb(GREEN), "{:?}", space);
+            assert_eq!(image.get_pixel(0, bottom).0, rgb(BLUE), "{:?}", space);
+            assert_eq!(image.get_pixel(right, bottom).0, rgb(WHITE), "{:?}", space);
+        }
+    }
+}
+
+#[test]
+fn bilinear_midpoints_mix_their_edges() {
+    let gradient = BilinearGradient::new(RED, GREEN, BLUE, WHITE, InterpolationSpace::Srgb);
+    assert_eq!(gradient.sample(0.5, 0.0), RGB::new(128, 128, 0));
+    assert_eq!(gradient.sample(0.0, 0.5), RGB::new(128, 0, 128));
+    assert_eq!(gradient.sample(0.5, 0.5), RGB::new(128, 128, 128));
+    // Coordinates outside the square are clamped to it.
+    assert_eq!(gradient.sample(-1.0, 2.0), BLUE);
+}
+
+#[test]
+fn grid_checks_its_size() {
+    let space = InterpolationSpace::Srgb;
+    let size = (10.0, 10.0);
+    assert_eq!(
+        MeshGradient::grid(0, 2, &[], size, space).err(),
+        Some(GradientError::EmptyMesh)
+    );
+    assert_eq!(
+        MeshGradient::grid(2, 0, &[], size, space).err(),
+        Some(GradientError::EmptyMesh)
+    );
+    assert_eq!(
+        MeshGradient::grid(2, 1, &[RED; 5], size, space).err(),
+        Some(GradientError::MeshSize {
+            expected: 6,
+            found: 5
+        })
+    );
+    assert_eq!(
+        MeshGradient::grid(2, 1, &[RED; 7], size, space).err(),
+        Some(GradientError::MeshSize {
+            expected: 6,
+            found: 7
+        })
+    );
+
+    let mesh = MeshGradient::grid(2, 1, &[RED; 6], size, space).unwrap();
+    assert_eq!(mesh.patches().len(), 2);
+    assert_eq!(mesh.patches()[1].top[0], (5.0, 0.0));
+    assert_eq!(mesh.patches()[1].bottom[3], (10.0, 10.0));
+}
+
+#[test]
+fn grid_covers_every_pixel() {
+    // Pixels outside every patch are left black, and none of these colours
+    // mixes to black.
+    let colours: Vec<RGB> = (0..20)
+        .map(|idx| RGB::new(40 + idx * 10, 250 - idx * 10, 60))
+        .collect();
+    for &(width, height) in &[(37, 23), (4, 5), (200, 3)] {
+        let size = (width as f64, height as f64);
+        let mesh = MeshGradient::grid(4, 3, &colours, size, InterpolationSpace::Srgb).unwrap();
+        let image = mesh.render(&RenderOptions::new(width, height));
+        for (x, y, pixel) in image.enumerate_pixels() {
+            assert_ne!(pixel.0, [0, 0, 0], "({}, {}) of {}×{}", x, y, width, height);
+        }
+    }
+}
+
+#[test]
+fn patches_are_coloured_from_their_corners() {
+    // A single patch over a 10×10 image: the centres of the corner pixels
+    // are a twentieth of the way in along each edge, so within about 26
+    // levels of the corner colours.
+    let patch = CoonsPatch::quad(
+        [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+        [RED, GREEN, WHITE, BLUE],
+    );
+    let mesh = MeshGradient::new(vec![patch], InterpolationSpace::Srgb);
+    let image = mesh.render(&RenderOptions::new(10, 10));
+    let near = |x: u32, y: u32, colour: RGB| {
+        let pixel = image.get_pixel(x, y).0;
+        let close = pixel
+            .iter()
+            .zip(&rgb(colour))
+            .all(|(&a, &b)| (a as i32 - b as i32).abs() <= 26);
+        assert!(close, "({}, {}): {:?} != {:?}", x, y, pixel, colour);
+    };
+    near(0, 0, RED);
+    near(9, 0, GREEN);
+    near(9, 9, WHITE);
+    near(0, 9, BLUE);
+
+    // Opaque meshes save with full alpha.
+    let file = std::env::temp_dir().join(format!("colour-{}-mesh.png", std::process::id()));
+    let options = RenderOptions::new(10, 10).with_format(PixelFormat::Rgba8);
+    mesh.generate_image(file.to_str().unwrap(), &options)
+        .unwrap();
+    let saved = image::open(&file).unwrap();
+    std::fs::remove_file(&file).unwrap();
+    assert_eq!(saved.color(), image::ColorType::Rgba8);
+    let saved = saved.into_rgba8();
+    for (pixel, opaque) in saved.pixels().zip(image.pixels()) {
+        let [r, g, b] = opaque.0;
+        assert_eq!(pixel.0, [r, g, b, 255]);
+    }
+}
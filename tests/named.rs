@@ -0,0 +1,87 @@
+use colour::RGB;
+
+fn assert_sorted_and_unique(table: &[(&str, RGB)]) {
+    for pair in table.windows(2) {
+        assert!(
+            pair[0].0 < pair[1].0,
+            "{:?} is not before {:?}",
+            pair[0].0,
+            pair[1].0
+        );
+    }
+}
+
+#[test]
+fn css_table_is_complete_sorted_and_unique() {
+    let table = RGB::named_colours();
+    assert_eq!(table.len(), 148);
+    assert_sorted_and_unique(table);
+    for (name, colour) in table {
+        assert_eq!(name.to_ascii_lowercase(), *name);
+        assert_eq!(RGB::from_name(name), Some(*colour), "{:?}", name);
+    }
+}
+
+#[test]
+fn from_name_ignores_case() {
+    let purple = Some(RGB::new(0x66, 0x33, 0x99));
+    for name in ["rebeccapurple", "RebeccaPurple", "REBECCAPURPLE"] {
+        assert_eq!(RGB::from_name(name), purple);
+    }
+    assert_eq!(RGB::from_name("grey"), RGB::from_name("gray"));
+    assert_eq!(RGB::from_name("rebecca purple"), None);
+    assert_eq!(RGB::from_name(""), None);
+    assert_eq!(RGB::from_name("notacolour"), None);
+}
+
+#[test]
+fn name_prefers_the_alphabetically_first_alias() {
+    assert_eq!(RGB::new(0x00, 0xff, 0xff).name(), Some("aqua"));
+    assert_eq!(RGB::new(0xff, 0x00, 0xff).name(), Some("fuchsia"));
+    assert_eq!(RGB::new(0x80, 0x80, 0x80).name(), Some("gray"));
+    assert_eq!(RGB::new(0x66, 0x33, 0x99).name(), Some("rebeccapurple"));
+    assert_eq!(RGB::new(0x01, 0x02, 0x03).name(), None);
+}
+
+#[test]
+fn nearest_name() {
+    assert_eq!(RGB::new(0xfe, 0x01, 0x00).nearest_name(), "red");
+    assert_eq!(RGB::new(0x67, 0x34, 0x98).nearest_name(), "rebeccapurple");
+    assert_eq!(RGB::new(0x02, 0x02, 0x02).nearest_name(), "black");
+    for (name, colour) in RGB::named_colours() {
+        assert_eq!(
+            RGB::from_name(colour.nearest_name()),
+            Some(*colour),
+            "{:?}",
+            name
+        );
+    }
+}
+
+#[cfg(feature = "x11")]
+#[test]
+fn x11_table_is_sorted_and_unique() {
+    let table = RGB::x11_colours();
+    assert_sorted_and_unique(table);
+    for (name, colour) in table {
+        assert_eq!(RGB::from_x11_name(name), Some(*colour), "{:?}", name);
+    }
+}
+
+#[cfg(feature = "x11")]
+#[test]
+fn x11_names_ignore_case_and_spaces() {
+    assert_eq!(
+        RGB::from_x11_name("DarkOliveGreen3"),
+        Some(RGB::new(0xa2, 0xcd, 0x5a))
+    );
+    assert_eq!(
+        RGB::from_x11_name("dark olive green"),
+        Some(RGB::new(0x55, 0x6b, 0x2f))
+    );
+    assert_eq!(RGB::from_x11_name("rebeccapurple"), None);
+    assert_eq!(
+        RGB::new(0xa2, 0xcd, 0x5a).nearest_x11_name(),
+        "darkolivegreen3"
+    );
+}
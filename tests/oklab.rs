@@ -0,0 +1,101 @@
+use colour::{
+    colour::{LinearRgb, Oklab, Oklch},
+    RGB,
+};
+
+mod common;
+
+fn assert_close<const N: usize>(actual: [f64; N], expected: [f64; N], tolerance: f64) {
+    for (a, e) in actual.iter().zip(&expected) {
+        assert!((a - e).abs() < tolerance, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn primaries_match_the_reference_values() {
+    // The values from Björn Ottosson's reference implementation.
+    let cases = [
+        (RGB::new(255, 0, 0), [0.62796, 0.22486, 0.12585]),
+        (RGB::new(0, 255, 0), [0.86644, -0.23389, 0.17950]),
+        (RGB::new(0, 0, 255), [0.45201, -0.03246, -0.31153]),
+        (RGB::new(255, 255, 255), [1.0, 0.0, 0.0]),
+        (RGB::new(0, 0, 0), [0.0, 0.0, 0.0]),
+    ];
+    for (rgb, expected) in cases {
+        let lab = Oklab::from(rgb);
+        assert_close([lab.l, lab.a, lab.b], expected, 1e-4);
+    }
+
+    let red = Oklch::from(RGB::new(255, 0, 0));
+    assert_close([red.l, red.c], [0.62796, 0.25768], 1e-4);
+    assert!((red.h - 29.23).abs() < 0.01, "{:?}", red);
+    let blue = Oklch::from(RGB::new(0, 0, 255));
+    assert_close([blue.l, blue.c, blue.h], [0.45201, 0.31321, 264.05], 1e-2);
+}
+
+#[test]
+fn rgb_round_trips_through_oklab() {
+    for rgb in common::colours(20_000) {
+        assert_eq!(
+            RGB::from(Oklab::from(rgb)),
+            rgb,
+            "via {:?}",
+            Oklab::from(rgb)
+        );
+    }
+}
+
+#[test]
+fn rgb_round_trips_through_oklch() {
+    for rgb in common::colours(20_000) {
+        assert_eq!(
+            RGB::from(Oklch::from(rgb)),
+            rgb,
+            "via {:?}",
+            Oklch::from(rgb)
+        );
+    }
+}
+
+#[test]
+fn linear_srgb_round_trips() {
+    // The published matrices are each other's inverses only to about six
+    // places.
+    for rgb in common::colours(1_000) {
+        let linear = LinearRgb::from(rgb);
+        let linear = [linear.red, linear.green, linear.blue];
+        assert_close(
+            Oklab::from_linear_srgb(linear).to_linear_srgb(),
+            linear,
+            1e-6,
+        );
+        assert_close(
+            Oklch::from_linear_srgb(linear).to_linear_srgb(),
+            linear,
+            1e-6,
+        );
+    }
+}
+
+#[test]
+fn oklch_is_oklab_in_polar_form() {
+    for rgb in common::colours(1_000) {
+        let (lab, lch) = (Oklab::from(rgb), Oklch::from(rgb));
+        let back = Oklab::from(lch);
+        assert_close([back.l, back.a, back.b], [lab.l, lab.a, lab.b], 1e-12);
+        assert!((0.0..360.0).contains(&lch.h), "{:?}", lch);
+        assert!((lch.c - lab.a.hypot(lab.b)).abs() < 1e-12, "{:?}", lch);
+    }
+}
+
+#[test]
+fn delta_e_is_the_euclidean_distance() {
+    let (black, white) = (
+        Oklab::from(RGB::new(0, 0, 0)),
+        Oklab::from(RGB::new(255, 255, 255)),
+    );
+    assert!((black.delta_e(&white) - 1.0).abs() < 1e-4);
+    let (a, b) = (Oklab::new(0.5, 0.1, -0.1), Oklab::new(0.5, 0.4, 0.3));
+    assert!((a.delta_e(&b) - 0.5).abs() < 1e-12);
+    assert_eq!(a.delta_e(&a), 0.0);
+}
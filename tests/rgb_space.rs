@@ -0,0 +1,56 @@
+use colour::{
+    colour::{AcesCg, AdobeRgb, DisplayP3, ProPhotoRgb, Rec2020, RgbSpace, Srgb, WhitePoint, WideRgb},
+    RGB,
+};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn assert_close<S: RgbSpace>(colour: WideRgb<S>, expected: [f64; 3]) {
+    let actual = [colour.red, colour.green, colour.blue];
+    for (a, e) in actual.iter().zip(&expected) {
+        assert!((a - e).abs() < 1e-4, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn srgb_red_in_display_p3() {
+    assert_close(WideRgb::<DisplayP3>::from(RGB::new(255, 0, 0)), [0.91749, 0.20029, 0.13856]);
+}
+
+#[test]
+fn display_p3_red_in_srgb() {
+    let red = WideRgb::<DisplayP3>::new(1.0, 0.0, 0.0);
+    assert_close(red.convert::<Srgb>(), [1.09307, -0.22674, -0.15013]);
+}
+
+#[test]
+fn white_maps_to_white() {
+    fn check<S: RgbSpace>() {
+        assert_close(WideRgb::<S>::from(RGB::new(255, 255, 255)), [1.0; 3]);
+        let xyz = WideRgb::<S>::new(1.0, 1.0, 1.0).to_xyz(WhitePoint::D65);
+        let d65 = WhitePoint::D65;
+        assert!((xyz.x - d65.x).abs() < 1e-9 && (xyz.z - d65.z).abs() < 1e-9, "{}", S::NAME);
+    }
+    check::<Srgb>();
+    check::<DisplayP3>();
+    check::<Rec2020>();
+    check::<AdobeRgb>();
+    check::<ProPhotoRgb>();
+    check::<AcesCg>();
+}
+
+#[test]
+fn rgb_round_trips_through_every_space() {
+    fn check<S: RgbSpace>(colours: &[RGB]) {
+        for &rgb in colours {
+            assert_eq!(WideRgb::<S>::from(rgb).to_rgb(), rgb, "{}", S::NAME);
+        }
+    }
+    let mut rng = StdRng::seed_from_u64(0x9a3);
+    let colours: Vec<RGB> = (0..2_000).map(|_| rng.gen()).collect();
+    check::<DisplayP3>(&colours);
+    check::<Rec2020>(&colours);
+    check::<AdobeRgb>(&colours);
+    check::<ProPhotoRgb>(&colours);
+    check::<AcesCg>(&colours);
+}
@@ -0,0 +1,207 @@
+use colour::{
+    colour::Rgb,
+    gradient::InterpolationSpace,
+    render::{Orientation, RenderOptions, Shape},
+    Gradient,
+};
+
+/// The position along the gradient of every pixel, row by row: a black to
+/// white gradient rendered as floats, whose channels are the positions,
+/// clamped to `0.0..=1.0`.
+fn positions(options: &RenderOptions) -> Vec<f64> {
+    let (black, white) = (
+        Rgb::<f64>::new(0.0, 0.0, 0.0),
+        Rgb::<f64>::new(1.0, 1.0, 1.0),
+    );
+    let gradient = Gradient::new(black, white, 2, InterpolationSpace::Srgb);
+    gradient
+        .render_as::<f64>(options)
+        .pixels()
+        .map(|pixel| pixel.0[0])
+        .collect()
+}
+
+/// Checks the positions of the listed pixels in a 101×101 image, centred
+/// on the middle pixel.
+fn assert_positions(shape: Shape, expected: &[((u32, u32), f64)]) {
+    let options = RenderOptions::new(101, 101).with_shape(shape);
+    let positions = positions(&options);
+    for &((x, y), t) in expected {
+        let actual = positions[y as usize * 101 + x as usize];
+        assert!(
+            (actual - t).abs() < 1e-12,
+            "{:?} at ({}, {}): {} != {}",
+            shape,
+            x,
+            y,
+            actual,
+            t
+        );
+    }
+}
+
+const CENTRE: (f64, f64) = (50.5, 50.5);
+
+#[test]
+fn conic_starts_up_and_sweeps_clockwise() {
+    assert_positions(
+        Shape::conic(CENTRE, 0.0),
+        &[
+            ((50, 10), 0.0),
+            ((90, 10), 0.125),
+            ((90, 50), 0.25),
+            ((50, 90), 0.5),
+            ((10, 50), 0.75),
+            ((10, 10), 0.875),
+        ],
+    );
+    // Starting from 90° puts the start on the right.
+    assert_positions(
+        Shape::conic(CENTRE, 90.0),
+        &[
+            ((90, 50), 0.0),
+            ((50, 90), 0.25),
+            ((10, 50), 0.5),
+            ((50, 10), 0.75),
+        ],
+    );
+    assert_positions(
+        Shape::conic(CENTRE, -90.0),
+        &[((10, 50), 0.0), ((50, 10), 0.25)],
+    );
+}
+
+#[test]
+fn radial_ends_at_each_radius() {
+    assert_positions(
+        Shape::ellipse(CENTRE, 40.0, 20.0),
+        &[
+            ((50, 50), 0.0),
+            ((70, 50), 0.5),
+            ((50, 60), 0.5),
+            ((90, 50), 1.0),
+            ((10, 50), 1.0),
+            ((50, 70), 1.0),
+            ((50, 30), 1.0),
+        ],
+    );
+    assert_positions(
+        Shape::circle(CENTRE, 10.0),
+        &[
+            ((50, 50), 0.0),
+            ((56, 58), 1.0),
+            ((42, 44), 1.0),
+            ((53, 54), 0.5),
+        ],
+    );
+}
+
+#[test]
+fn diamond_ends_at_the_taxicab_radius() {
+    assert_positions(
+        Shape::diamond(CENTRE, 40.0, 20.0),
+        &[
+            ((50, 50), 0.0),
+            ((60, 55), 0.5),
+            ((90, 50), 1.0),
+            ((50, 30), 1.0),
+            ((70, 60), 1.0),
+            ((30, 40), 1.0),
+        ],
+    );
+}
+
+#[test]
+fn shapes_without_size_end_everywhere() {
+    for &shape in &[
+        Shape::circle(CENTRE, 0.0),
+        Shape::circle(CENTRE, -5.0),
+        Shape::circle(CENTRE, f64::NAN),
+        Shape::ellipse(CENTRE, 10.0, 0.0),
+        Shape::ellipse(CENTRE, -10.0, 10.0),
+        Shape::diamond(CENTRE, 0.0, 10.0),
+        Shape::diamond(CENTRE, 10.0, -0.0),
+    ] {
+        let options = RenderOptions::new(101, 101).with_shape(shape);
+        assert!(positions(&options).iter().all(|&t| t == 1.0), "{:?}", shape);
+    }
+}
+
+/// The positions of the top-left, top-right, bottom-right and bottom-left
+/// pixels of a `width` by `height` linear gradient.
+fn corners(width: u32, height: u32, orientation: Orientation) -> [f64; 4] {
+    let options = RenderOptions::new(width, height).with_orientation(orientation);
+    let positions = positions(&options);
+    let at = |x: u32, y: u32| positions[(y * width + x) as usize];
+    let (right, bottom) = (width - 1, height - 1);
+    [at(0, 0), at(right, 0), at(right, bottom), at(0, bottom)]
+}
+
+fn assert_corners(width: u32, height: u32, orientation: Orientation, expected: [f64; 4]) {
+    let actual = corners(width, height, orientation);
+    for (a, e) in actual.iter().zip(&expected) {
+        assert!(
+            (a - e).abs() < 1e-12,
+            "{:?} over {}×{}: {:?} != {:?}",
+            orientation,
+            width,
+            height,
+            actual,
+            expected
+        );
+    }
+}
+
+#[test]
+fn linear_corners_follow_the_orientation() {
+    for &(width, height) in &[(2, 2), (5, 5), (11, 4), (3, 40)] {
+        // Diagonals run from one corner to the opposite one, and cross the
+        // other two in proportion to the image's sides.
+        let (w, h) = ((width - 1) as f64, (height - 1) as f64);
+        let (across, down) = (w / (w + h), h / (w + h));
+        let cases = [
+            (Orientation::Vertical, [0.0, 0.0, 1.0, 1.0]),
+            (Orientation::Horizontal, [0.0, 1.0, 1.0, 0.0]),
+            (Orientation::Angle(0.0), [1.0, 1.0, 0.0, 0.0]),
+            (Orientation::Angle(180.0), [0.0, 0.0, 1.0, 1.0]),
+            (Orientation::Angle(45.0), [down, 1.0, across, 0.0]),
+            (Orientation::Angle(135.0), [0.0, across, 1.0, down]),
+            // Negative angles and extra turns give the same directions.
+            (Orientation::Angle(-90.0), [1.0, 0.0, 0.0, 1.0]),
+            (Orientation::Angle(-315.0), [down, 1.0, across, 0.0]),
+            (Orientation::Angle(450.0), [0.0, 1.0, 1.0, 0.0]),
+        ];
+        for (orientation, expected) in cases {
+            assert_corners(width, height, orientation, expected);
+        }
+    }
+    // In a square, diagonals cross the other corners halfway.
+    assert_corners(5, 5, Orientation::Angle(45.0), [0.5, 1.0, 0.5, 0.0]);
+}
+
+#[test]
+fn linear_gradients_with_no_length_take_the_start() {
+    // A single pixel, or a line of pixels across the gradient, has no
+    // length to spread the gradient over.
+    for orientation in [
+        Orientation::Vertical,
+        Orientation::Horizontal,
+        Orientation::Angle(45.0),
+        Orientation::Angle(-90.0),
+    ] {
+        assert_corners(1, 1, orientation, [0.0; 4]);
+    }
+    assert_corners(7, 1, Orientation::Vertical, [0.0; 4]);
+    assert_corners(1, 7, Orientation::Horizontal, [0.0; 4]);
+    assert_corners(1, 7, Orientation::Angle(-90.0), [0.0; 4]);
+    // But a diagonal still runs along it.
+    assert_corners(1, 7, Orientation::Angle(45.0), [1.0, 1.0, 0.0, 0.0]);
+
+    // Empty images have no pixels to place.
+    for &(width, height) in &[(0, 0), (0, 5), (5, 0)] {
+        for orientation in [Orientation::Vertical, Orientation::Angle(45.0)] {
+            let options = RenderOptions::new(width, height).with_orientation(orientation);
+            assert!(positions(&options).is_empty());
+        }
+    }
+}
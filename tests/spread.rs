@@ -0,0 +1,116 @@
+use colour::{
+    colour::Rgb,
+    gradient::{InterpolationSpace, Spread},
+    Gradient,
+};
+
+/// Where a black to white gradient with the given spread samples `t`: its
+/// channels are the position it was brought back to.
+fn spread(spread: Spread, t: f64) -> f64 {
+    let (black, white) = (
+        Rgb::<f64>::new(0.0, 0.0, 0.0),
+        Rgb::<f64>::new(1.0, 1.0, 1.0),
+    );
+    let gradient = Gradient::new(black, white, 2, InterpolationSpace::Srgb).with_spread(spread);
+    gradient.sample_as::<f64>(t).to_tuple().0
+}
+
+fn assert_spread(mode: Spread, cases: &[(f64, f64)]) {
+    for &(t, expected) in cases {
+        let actual = spread(mode, t);
+        assert!(
+            (actual - expected).abs() < 1e-12,
+            "{:?} at {}: {} != {}",
+            mode,
+            t,
+            actual,
+            expected
+        );
+    }
+}
+
+#[test]
+fn clamp_extends_the_ends() {
+    assert_eq!(Spread::default(), Spread::Clamp);
+    assert_spread(
+        Spread::Clamp,
+        &[
+            (-5.0, 0.0),
+            (-0.25, 0.0),
+            (0.0, 0.0),
+            (0.25, 0.25),
+            (1.0, 1.0),
+            (1.25, 1.0),
+            (7.0, 1.0),
+        ],
+    );
+}
+
+#[test]
+fn repeat_starts_each_copy_afresh() {
+    assert_spread(
+        Spread::Repeat,
+        &[
+            (0.0, 0.0),
+            (0.25, 0.25),
+            (1.25, 0.25),
+            (3.5, 0.5),
+            (-0.25, 0.75),
+            (-1.75, 0.25),
+            (-2.5, 0.5),
+        ],
+    );
+}
+
+#[test]
+fn repeat_ends_each_copy_on_the_end_colour() {
+    // Every whole number but zero ends a copy, as 1.0 ends the first.
+    assert_spread(
+        Spread::Repeat,
+        &[
+            (1.0, 1.0),
+            (2.0, 1.0),
+            (10.0, 1.0),
+            (-1.0, 1.0),
+            (-3.0, 1.0),
+            (-0.0, 0.0),
+        ],
+    );
+    // Just past a whole number starts the next copy.
+    assert!(spread(Spread::Repeat, 2.0 + 1e-9) < 1e-6);
+    assert!(spread(Spread::Repeat, 2.0 - 1e-9) > 1.0 - 1e-6);
+}
+
+#[test]
+fn mirror_reverses_every_other_copy() {
+    assert_spread(
+        Spread::Mirror,
+        &[
+            (0.0, 0.0),
+            (0.25, 0.25),
+            (1.0, 1.0),
+            (1.25, 0.75),
+            (2.0, 0.0),
+            (2.25, 0.25),
+            (3.0, 1.0),
+            (-0.25, 0.25),
+            (-1.0, 1.0),
+            (-1.25, 0.75),
+            (-2.0, 0.0),
+        ],
+    );
+}
+
+#[test]
+fn non_finite_positions_clamp() {
+    for mode in [Spread::Clamp, Spread::Repeat, Spread::Mirror] {
+        assert_spread(
+            mode,
+            &[
+                (f64::INFINITY, 1.0),
+                (f64::NEG_INFINITY, 0.0),
+                (f64::NAN, 0.0),
+            ],
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use colour::{
+    gradient::{ColourStop, GradientError, InterpolationSpace},
+    Gradient, RGB,
+};
+
+const BLACK: RGB = RGB::new(0, 0, 0);
+const WHITE: RGB = RGB::new(255, 255, 255);
+
+fn gradient(stops: Vec<ColourStop>) -> Gradient {
+    Gradient::from_stops(stops, 2, InterpolationSpace::Srgb).unwrap()
+}
+
+/// The grey level at `t`, as a fraction of white.
+fn level(gradient: &Gradient, t: f64) -> f64 {
+    gradient.sample_as::<f64>(t).to_tuple().0
+}
+
+fn assert_level(gradient: &Gradient, t: f64, expected: f64) {
+    let actual = level(gradient, t);
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "at {}: {} != {}",
+        t,
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn hint_is_the_halfway_point() {
+    for hint in [0.1, 0.25, 0.5, 0.8] {
+        let g = gradient(vec![
+            ColourStop::new(0.0, BLACK).with_hint(hint),
+            ColourStop::new(1.0, WHITE),
+        ]);
+        assert_level(&g, hint, 0.5);
+        assert_level(&g, 0.0, 0.0);
+        assert_level(&g, 1.0, 1.0);
+    }
+}
+
+#[test]
+fn hint_follows_the_css_power_curve() {
+    let g = gradient(vec![
+        ColourStop::new(0.0, BLACK).with_hint(0.25),
+        ColourStop::new(1.0, WHITE),
+    ]);
+    // The exponent takes 0.25 to 0.5: ln 0.5 / ln 0.25 = 0.5.
+    for t in [0.1, 0.5, 0.9] {
+        assert_level(&g, t, t.sqrt());
+    }
+}
+
+#[test]
+fn hint_is_relative_to_its_segment() {
+    let g = gradient(vec![
+        ColourStop::new(0.0, BLACK),
+        ColourStop::new(0.5, BLACK).with_hint(0.6),
+        ColourStop::new(1.0, WHITE),
+    ]);
+    assert_level(&g, 0.6, 0.5);
+    assert_level(&g, 0.5, 0.0);
+}
+
+#[test]
+fn hint_on_either_stop_makes_a_hard_edge() {
+    let at_start = gradient(vec![
+        ColourStop::new(0.0, BLACK).with_hint(0.0),
+        ColourStop::new(1.0, WHITE),
+    ]);
+    assert_level(&at_start, 0.0, 0.0);
+    assert_level(&at_start, 0.01, 1.0);
+
+    let at_end = gradient(vec![
+        ColourStop::new(0.0, BLACK).with_hint(1.0),
+        ColourStop::new(1.0, WHITE),
+    ]);
+    assert_level(&at_end, 0.99, 0.0);
+    assert_level(&at_end, 1.0, 1.0);
+}
+
+#[test]
+fn hint_past_the_next_stop_moves_it_up() {
+    let g = gradient(vec![
+        ColourStop::new(0.0, BLACK).with_hint(1.5),
+        ColourStop::new(1.0, WHITE),
+    ]);
+    let positions: Vec<f64> = g.stops().iter().map(|stop| stop.position).collect();
+    assert_eq!(positions, [0.0, 1.5]);
+    assert_eq!(g.stops()[0].hint, Some(1.5));
+    // The white stop now lies past the end, so the gradient never reaches it.
+    assert_level(&g, 1.0, 0.0);
+    assert_eq!(g.end(), WHITE);
+}
+
+#[test]
+fn hint_before_its_stop_moves_up_to_it() {
+    let g = gradient(vec![
+        ColourStop::new(0.5, BLACK).with_hint(0.2),
+        ColourStop::new(1.0, WHITE),
+    ]);
+    assert_eq!(g.stops()[0].hint, Some(0.5));
+    assert_level(&g, 0.6, 1.0);
+}
+
+#[test]
+fn stops_out_of_order_are_moved_up() {
+    let g = gradient(vec![
+        ColourStop::new(0.6, BLACK),
+        ColourStop::new(0.2, WHITE),
+        ColourStop::new(0.9, BLACK),
+    ]);
+    let positions: Vec<f64> = g.stops().iter().map(|stop| stop.position).collect();
+    assert_eq!(positions, [0.6, 0.6, 0.9]);
+}
+
+#[test]
+fn equal_positions_make_a_hard_edge() {
+    let g = gradient(vec![
+        ColourStop::new(0.0, BLACK),
+        ColourStop::new(0.5, BLACK),
+        ColourStop::new(0.5, WHITE),
+        ColourStop::new(1.0, WHITE),
+    ]);
+    assert_level(&g, 0.4999, 0.0);
+    assert_level(&g, 0.5, 1.0);
+}
+
+#[test]
+fn hint_on_the_last_stop_has_no_effect() {
+    let plain = gradient(vec![
+        ColourStop::new(0.0, BLACK),
+        ColourStop::new(1.0, WHITE),
+    ]);
+    let hinted = gradient(vec![
+        ColourStop::new(0.0, BLACK),
+        ColourStop::new(1.0, WHITE).with_hint(2.0),
+    ]);
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        assert_level(&hinted, t, level(&plain, t));
+    }
+}
+
+#[test]
+fn non_finite_hints_are_rejected() {
+    let stops = vec![
+        ColourStop::new(0.0, BLACK).with_hint(f64::NAN),
+        ColourStop::new(1.0, WHITE),
+    ];
+    assert!(matches!(
+        Gradient::from_stops(stops, 2, InterpolationSpace::Srgb),
+        Err(GradientError::InvalidHint { index: 0, .. })
+    ));
+}